@@ -0,0 +1,86 @@
+use std::ops::{Add, Div, Mul, Sub};
+use num_traits::{One, Zero};
+
+// The arithmetic bounds live on `&Self`, which a plain supertrait list cannot
+// express, so these are aliases: anything with the right reference operators
+// is a semiring or ring, including square matrices over one.
+pub trait AdditiveMonoid = Zero
+where
+    for<'a, 'b> &'a Self: Add<&'b Self, Output = Self>;
+
+pub trait AdditiveGroup = AdditiveMonoid
+where
+    for<'a, 'b> &'a Self: Sub<&'b Self, Output = Self>;
+
+pub trait Semiring = AdditiveMonoid + One
+where
+    for<'a, 'b> &'a Self: Mul<&'b Self, Output = Self>;
+
+pub trait Ring = Semiring + AdditiveGroup;
+
+pub trait DivisionRing = Ring
+where
+    for<'a, 'b> &'a Self: Div<&'b Self, Output = Self>;
+
+// Commutativity can't be read off the operators, so it is opted into. Only
+// the Euclidean algorithms (gcd, Hermite and Smith forms) require it:
+// determinants and characteristic polynomials stay on Ring so that they also
+// accept block matrices, whose entries commute only in special cases.
+pub trait CommutativeRing: Ring {}
+
+pub trait EuclideanDomain: CommutativeRing + Clone + PartialEq {
+    // (q, r) with self = q * other + r, where r is zero or smaller than other
+    fn div_rem(&self, other: &Self) -> (Self, Self);
+
+    // A unit u such that u * self is the canonical associate of self
+    fn normalizing_unit(&self) -> Self;
+
+    // Greatest common divisor, up to multiplication by a unit
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+}
+
+macro_rules! impl_euclidean_domain_signed {
+    ($($t:ty),*) => {
+        $(
+            impl EuclideanDomain for $t {
+                fn div_rem(&self, other: &Self) -> (Self, Self) {
+                    (self.div_euclid(*other), self.rem_euclid(*other))
+                }
+
+                fn normalizing_unit(&self) -> Self {
+                    match *self < 0 {
+                        true => -1,
+                        false => 1
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_domain_signed!(i8, i16, i32, i64, i128, isize);
+
+// Division by any nonzero element is exact. Polynomial division needs it;
+// elimination only asks for DivisionRing. Floats and GF opt in.
+pub trait Field: CommutativeRing + DivisionRing + Clone + PartialEq {}
+
+macro_rules! impl_commutative_ring {
+    ($($t:ty),*) => {
+        $(
+            impl CommutativeRing for $t {}
+        )*
+    };
+}
+
+impl_commutative_ring!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl Field for f32 {}
+impl Field for f64 {}
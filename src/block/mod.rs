@@ -0,0 +1,117 @@
+use std::array::from_fn;
+
+use num_traits::Zero;
+
+use crate::matrix::Matrix;
+
+// As in `nested`, the combined dimensions are extra const parameters checked
+// at compile time rather than `{M + P}` expressions.
+
+pub fn direct_sum<R, const M: usize, const N: usize, const P: usize, const Q: usize, const ROWS: usize, const COLS: usize>(
+    a: Matrix<R, M, N>,
+    b: Matrix<R, P, Q>
+) -> Matrix<R, ROWS, COLS>
+where
+    R: Zero
+{
+    const {
+        assert!(ROWS == M + P, "direct sum must have M + P rows");
+        assert!(COLS == N + Q, "direct sum must have N + Q columns");
+    }
+    let mut a = a.vals.into_iter();
+    let mut b = b.vals.into_iter();
+    let vals = from_fn(|i| {
+        match i < M {
+            true => {
+                let mut row = a.next().unwrap().into_iter();
+                from_fn(|j| match j < N {
+                    true => row.next().unwrap(),
+                    false => R::zero()
+                })
+            }
+            false => {
+                let mut row = b.next().unwrap().into_iter();
+                from_fn(|j| match j < N {
+                    true => R::zero(),
+                    false => row.next().unwrap()
+                })
+            }
+        }
+    });
+    Matrix {
+        vals
+    }
+}
+
+pub fn hstack<R, const ROWS: usize, const N: usize, const Q: usize, const COLS: usize>(
+    a: Matrix<R, ROWS, N>,
+    b: Matrix<R, ROWS, Q>
+) -> Matrix<R, ROWS, COLS> {
+    const {
+        assert!(COLS == N + Q, "horizontal stack must have N + Q columns");
+    }
+    let mut a = a.vals.into_iter();
+    let mut b = b.vals.into_iter();
+    let vals = from_fn(|_| {
+        let mut left = a.next().unwrap().into_iter();
+        let mut right = b.next().unwrap().into_iter();
+        from_fn(|j| match j < N {
+            true => left.next().unwrap(),
+            false => right.next().unwrap()
+        })
+    });
+    Matrix {
+        vals
+    }
+}
+
+pub fn vstack<R, const M: usize, const P: usize, const COLS: usize, const ROWS: usize>(
+    a: Matrix<R, M, COLS>,
+    b: Matrix<R, P, COLS>
+) -> Matrix<R, ROWS, COLS> {
+    const {
+        assert!(ROWS == M + P, "vertical stack must have M + P rows");
+    }
+    let mut rows = a.vals.into_iter().chain(b.vals);
+    let vals = from_fn(|_| rows.next().unwrap());
+    Matrix {
+        vals
+    }
+}
+
+fn split_rows<R, const COLS: usize, const M: usize, const P: usize, const ROWS: usize>(
+    vals: [[R; COLS]; ROWS]
+) -> ([[R; COLS]; M], [[R; COLS]; P]) {
+    let mut rows = vals.into_iter();
+    let top = from_fn(|_| rows.next().unwrap());
+    let bottom = from_fn(|_| rows.next().unwrap());
+    (top, bottom)
+}
+
+fn split_columns<R, const ROWS: usize, const N: usize, const Q: usize, const COLS: usize>(
+    vals: [[R; COLS]; ROWS]
+) -> (Matrix<R, ROWS, N>, Matrix<R, ROWS, Q>) {
+    let mut rows = vals.map(|row| row.into_iter());
+    let left = from_fn(|i| from_fn(|_| rows[i].next().unwrap()));
+    let right = from_fn(|i| from_fn(|_| rows[i].next().unwrap()));
+    (Matrix { vals: left }, Matrix { vals: right })
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS> {
+    // Splits into [[A, B], [C, D]] with A of shape M x N and D of shape P x Q
+    pub fn split_blocks<const M: usize, const N: usize, const P: usize, const Q: usize>(self) -> (
+        Matrix<R, M, N>,
+        Matrix<R, M, Q>,
+        Matrix<R, P, N>,
+        Matrix<R, P, Q>
+    ) {
+        const {
+            assert!(ROWS == M + P, "row split must satisfy M + P = ROWS");
+            assert!(COLS == N + Q, "column split must satisfy N + Q = COLS");
+        }
+        let (top, bottom) = split_rows::<R, COLS, M, P, ROWS>(self.vals);
+        let (top_left, top_right) = split_columns(top);
+        let (bottom_left, bottom_right) = split_columns(bottom);
+        (top_left, top_right, bottom_left, bottom_right)
+    }
+}
@@ -0,0 +1,124 @@
+use std::array::from_fn;
+
+use crate::{algebra::Ring, matrix::{Matrix, MatrixRef}};
+
+// Berkowitz's algorithm: coefficients of det(xI - A), highest degree first.
+// Only uses ring operations, so it is valid over any commutative ring.
+// Entries are read through `entry` so that minors can be used without copying.
+pub(crate) fn berkowitz<'v, R>(dim: usize, entry: impl Fn(usize, usize) -> &'v R) -> Vec<R>
+where
+    R: Ring + 'v
+{
+    let mut poly = vec![R::one()];
+    for r in (0..dim).rev() {
+        let m = dim - r - 1;
+        let a = entry(r, r);
+
+        // Toeplitz column: 1, -a, -R C, -R M C, ..., -R M^(m-1) C
+        let mut toeplitz = Vec::with_capacity(m + 2);
+        toeplitz.push(R::one());
+        toeplitz.push(&R::zero() - a);
+        let mut row: Vec<R> = (r + 1..dim)
+            .map(|j| entry(r, j) + &R::zero())
+            .collect();
+        for _ in 0..m {
+            let rc = row.iter()
+                .zip(r + 1..dim)
+                .map(|(x, i)| x * entry(i, r))
+                .fold(R::zero(), |x, y| &x + &y);
+            toeplitz.push(&R::zero() - &rc);
+            row = (r + 1..dim)
+                .map(|j| row.iter()
+                    .zip(r + 1..dim)
+                    .map(|(x, i)| x * entry(i, j))
+                    .fold(R::zero(), |x, y| &x + &y))
+                .collect();
+        }
+
+        poly = (0..m + 2)
+            .map(|i| (0..=i.min(m))
+                .map(|j| &toeplitz[i - j] * &poly[j])
+                .fold(R::zero(), |x, y| &x + &y))
+            .collect();
+    }
+    poly
+}
+
+impl<'a, R, const DIM: usize> MatrixRef<'a, R, DIM, DIM> {
+    // Coefficients of det(xI - A) in ascending order of degree
+    pub fn characteristic_polynomial(&self) -> Vec<R>
+    where
+        R: Ring
+    {
+        let vals = self.vals;
+        let mut coefficients = berkowitz(DIM, |i, j| &vals[i][j]);
+        coefficients.reverse();
+        coefficients
+    }
+
+    pub fn determinant(&self) -> R
+    where
+        R: Ring
+    {
+        let v = self.vals;
+        match DIM {
+            0 => R::one(),
+            1 => &v[0][0] + &R::zero(),
+            2 => &(&v[0][0] * &v[1][1]) - &(&v[0][1] * &v[1][0]),
+            3 => {
+                let minor = |j: usize, k: usize| {
+                    &(&v[1][j] * &v[2][k]) - &(&v[1][k] * &v[2][j])
+                };
+                let plus = &(&v[0][0] * &minor(1, 2)) + &(&v[0][2] * &minor(0, 1));
+                &plus - &(&v[0][1] * &minor(0, 2))
+            }
+            _ => {
+                let constant = berkowitz(DIM, |i, j| &v[i][j]).pop().unwrap();
+                match DIM % 2 {
+                    0 => constant,
+                    _ => &R::zero() - &constant
+                }
+            }
+        }
+    }
+}
+
+impl<R, const DIM: usize> Matrix<R, DIM, DIM> {
+    pub fn characteristic_polynomial(&self) -> Vec<R>
+    where
+        R: Ring
+    {
+        self.as_ref().characteristic_polynomial()
+    }
+
+    pub fn determinant(&self) -> R
+    where
+        R: Ring
+    {
+        self.as_ref().determinant()
+    }
+}
+
+impl<R, const DIM: usize> Matrix<R, DIM, DIM> {
+    // Transpose of the cofactor matrix, so that A adj(A) = adj(A) A = det(A) I
+    pub fn adjugate(&self) -> Self
+    where
+        R: Ring
+    {
+        let vals = from_fn(|i|
+            from_fn(|j| self.cofactor(j, i))
+        );
+        Self {
+            vals
+        }
+    }
+
+    // adj(A) det(A)^-1, provided `unit_inverse` can invert the determinant
+    pub fn try_inverse_with(&self, unit_inverse: impl Fn(&R) -> Option<R>) -> Option<Self>
+    where
+        R: Ring
+    {
+        let det_inverse = unit_inverse(&self.determinant())?;
+        Some(self.adjugate().scale_right(&det_inverse))
+    }
+}
@@ -0,0 +1,117 @@
+use std::array::from_fn;
+
+use crate::{algebra::DivisionRing, matrix::Matrix};
+
+// Row reduces `lhs` to (reduced) row echelon form, applying the same row
+// operations to `rhs`. Returns the pivot columns in order.
+fn eliminate<R, const ROWS: usize, const COLS: usize, const K: usize>(
+    lhs: &mut [[R; COLS]; ROWS],
+    rhs: &mut [[R; K]; ROWS],
+    reduced: bool
+) -> Vec<usize>
+where
+    R: DivisionRing + Clone + PartialEq
+{
+    let mut pivots = Vec::new();
+    for col in 0..COLS {
+        let row = pivots.len();
+        if row == ROWS {
+            break;
+        }
+        let Some(pivot) = (row..ROWS).find(|&r| !lhs[r][col].is_zero()) else {
+            continue;
+        };
+        lhs.swap(row, pivot);
+        rhs.swap(row, pivot);
+
+        if reduced {
+            let scale = &R::one() / &lhs[row][col];
+            lhs[row].iter_mut().for_each(|x| *x = &*x * &scale);
+            rhs[row].iter_mut().for_each(|x| *x = &*x * &scale);
+        }
+
+        let pivot_lhs = lhs[row].clone();
+        let pivot_rhs = rhs[row].clone();
+        let targets = match reduced {
+            true => 0..ROWS,
+            false => row + 1..ROWS
+        };
+        for r in targets.filter(|&r| r != row) {
+            if lhs[r][col].is_zero() {
+                continue;
+            }
+            let factor = &lhs[r][col] / &pivot_lhs[col];
+            for (x, p) in lhs[r].iter_mut().zip(&pivot_lhs) {
+                *x = &*x - &(&factor * p);
+            }
+            for (x, p) in rhs[r].iter_mut().zip(&pivot_rhs) {
+                *x = &*x - &(&factor * p);
+            }
+        }
+        pivots.push(col);
+    }
+    pivots
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS>
+where
+    R: DivisionRing + Clone + PartialEq
+{
+    pub fn row_echelon(&self) -> Self {
+        let mut vals = self.vals.clone();
+        eliminate::<R, ROWS, COLS, 0>(&mut vals, &mut from_fn(|_| []), false);
+        Self {
+            vals
+        }
+    }
+
+    pub fn reduced_row_echelon(&self) -> Self {
+        let mut vals = self.vals.clone();
+        eliminate::<R, ROWS, COLS, 0>(&mut vals, &mut from_fn(|_| []), true);
+        Self {
+            vals
+        }
+    }
+
+    pub fn rank(&self) -> usize {
+        let mut vals = self.vals.clone();
+        eliminate::<R, ROWS, COLS, 0>(&mut vals, &mut from_fn(|_| []), false).len()
+    }
+
+    // Some solution x of A x = b, with free variables set to zero, or None if
+    // the system is inconsistent
+    pub fn solve<const K: usize>(&self, b: &Matrix<R, ROWS, K>) -> Option<Matrix<R, COLS, K>> {
+        let mut lhs = self.vals.clone();
+        let mut rhs = b.vals.clone();
+        let pivots = eliminate(&mut lhs, &mut rhs, true);
+        if rhs[pivots.len()..].iter().flatten().any(|x| !x.is_zero()) {
+            return None;
+        }
+        let mut vals: [[R; K]; COLS] = from_fn(|_| from_fn(|_| R::zero()));
+        for (row, col) in pivots.into_iter().enumerate() {
+            vals[col] = rhs[row].clone();
+        }
+        Some(Matrix {
+            vals
+        })
+    }
+}
+
+impl<R, const DIM: usize> Matrix<R, DIM, DIM>
+where
+    R: DivisionRing + Clone + PartialEq
+{
+    pub fn try_inverse(&self) -> Option<Self> {
+        let mut lhs = self.vals.clone();
+        let mut rhs = from_fn(|i|
+            from_fn(|j| match i == j {
+                true => R::one(),
+                false => R::zero()
+            })
+        );
+        match eliminate(&mut lhs, &mut rhs, true).len() == DIM {
+            true => Some(Self { vals: rhs }),
+            false => None
+        }
+    }
+}
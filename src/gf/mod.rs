@@ -0,0 +1,456 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::{One, Zero};
+
+use crate::algebra::{CommutativeRing, Field};
+
+// An element of the finite field GF(P^N), stored as the coefficients of a
+// polynomial of degree below N, lowest degree first, reduced modulo a monic
+// irreducible polynomial x^N + c_{N-1} x^{N-1} + ... + c_0. MODULUS encodes
+// c_0, ..., c_{N-1} as the base-P digits of an integer, so for P = 2 it is the
+// usual bit pattern without the leading term, and 0 picks a default. The
+// modulus is checked, or searched for, at compile time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GF<const P: u64, const N: usize, const MODULUS: u128 = 0>([u64; N]);
+
+const fn mul_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 * b as u128) % p as u128) as u64
+}
+
+const fn add_mod(a: u64, b: u64, p: u64) -> u64 {
+    ((a as u128 + b as u128) % p as u128) as u64
+}
+
+const fn sub_mod(a: u64, b: u64, p: u64) -> u64 {
+    add_mod(a, p - b, p)
+}
+
+const fn pow_mod(mut a: u64, mut exp: u64, p: u64) -> u64 {
+    let mut result = 1 % p;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, a, p);
+        }
+        a = mul_mod(a, a, p);
+        exp >>= 1;
+    }
+    result
+}
+
+// Miller-Rabin with a witness set that is deterministic for all u64
+const fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n.is_multiple_of(WITNESSES[i]) {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    let mut i = 0;
+    'witness: while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+// a * b modulo the monic polynomial with low coefficients `modulus`, by
+// Horner's rule so that nothing of degree N or more is ever stored
+const fn poly_mul<const P: u64, const N: usize>(a: &[u64; N], b: &[u64; N], modulus: &[u64; N]) -> [u64; N] {
+    let mut result = [0; N];
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        // result *= x, replacing x^N by -(c_{N-1} x^{N-1} + ... + c_0)
+        let top = result[N - 1];
+        let mut j = N - 1;
+        while j > 0 {
+            result[j] = sub_mod(result[j - 1], mul_mod(top, modulus[j], P), P);
+            j -= 1;
+        }
+        result[0] = sub_mod(0, mul_mod(top, modulus[0], P), P);
+        // result += a_i b
+        let mut j = 0;
+        while j < N {
+            result[j] = add_mod(result[j], mul_mod(a[i], b[j], P), P);
+            j += 1;
+        }
+    }
+    result
+}
+
+const fn poly_pow<const P: u64, const N: usize>(a: &[u64; N], mut exp: u64, modulus: &[u64; N]) -> [u64; N] {
+    let mut result = [0; N];
+    result[0] = 1 % P;
+    let mut base = *a;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = poly_mul::<P, N>(&result, &base, modulus);
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = poly_mul::<P, N>(&base, &base, modulus);
+        }
+    }
+    result
+}
+
+// Index of the highest nonzero coefficient, or None for zero
+const fn degree<const N: usize>(a: &[u64; N]) -> Option<usize> {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != 0 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+// Reduces a modulo b, where b has degree db
+const fn poly_rem<const P: u64, const N: usize>(a: &mut [u64; N], b: &[u64; N], db: usize) {
+    let inverse = pow_mod(b[db], P - 2, P);
+    let mut d = N;
+    while d > db {
+        d -= 1;
+        let factor = mul_mod(a[d], inverse, P);
+        if factor != 0 {
+            let mut i = 0;
+            while i <= db {
+                a[d - db + i] = sub_mod(a[d - db + i], mul_mod(factor, b[i], P), P);
+                i += 1;
+            }
+        }
+    }
+}
+
+// Whether h shares no factor with the modulus, by Euclid's algorithm. The
+// first step reduces the modulus itself, whose x^N term is implicit.
+const fn is_coprime<const P: u64, const N: usize>(h: &[u64; N], modulus: &[u64; N]) -> bool {
+    let Some(mut db) = degree(h) else {
+        return false;
+    };
+    let mut b = *h;
+    let mut a = *modulus;
+    let factor = pow_mod(b[db], P - 2, P);
+    let mut i = 0;
+    while i < db {
+        a[N - db + i] = sub_mod(a[N - db + i], mul_mod(factor, b[i], P), P);
+        i += 1;
+    }
+    while db > 0 {
+        poly_rem::<P, N>(&mut a, &b, db);
+        let Some(da) = degree(&a) else {
+            return false;
+        };
+        (a, b, db) = (b, a, da);
+    }
+    true
+}
+
+// Ben-Or's test: a reducible polynomial of degree N has an irreducible factor
+// of some degree k <= N / 2, which then divides x^(P^k) - x
+const fn is_irreducible<const P: u64, const N: usize>(modulus: &[u64; N]) -> bool {
+    if N == 1 {
+        return true;
+    }
+    let mut x = [0; N];
+    x[1] = 1;
+    let mut frobenius = x;
+    let mut k = 1;
+    while k <= N / 2 {
+        frobenius = poly_pow::<P, N>(&frobenius, P, modulus);
+        let mut h = frobenius;
+        h[1] = sub_mod(h[1], 1, P);
+        if !is_coprime::<P, N>(&h, modulus) {
+            return false;
+        }
+        k += 1;
+    }
+    true
+}
+
+// Over GF(2) a polynomial of degree at most 127 fits in the bits of a u128,
+// so the default search for P = 2 runs on carry-less arithmetic instead.
+// Here the modulus includes its x^n bit.
+const fn binary_mul(a: u128, b: u128, modulus: u128, n: usize) -> u128 {
+    let mut result = 0;
+    let mut i = n;
+    while i > 0 {
+        i -= 1;
+        result <<= 1;
+        if (result >> n) & 1 == 1 {
+            result ^= modulus;
+        }
+        if (a >> i) & 1 == 1 {
+            result ^= b;
+        }
+    }
+    result
+}
+
+const fn binary_is_coprime(mut a: u128, mut b: u128) -> bool {
+    while b != 0 {
+        let db = 127 - b.leading_zeros();
+        while a != 0 && 127 - a.leading_zeros() >= db {
+            a ^= b << (127 - a.leading_zeros() - db);
+        }
+        (a, b) = (b, a);
+    }
+    a == 1
+}
+
+const fn binary_default_modulus<const N: usize>() -> [u64; N] {
+    let mut low: u128 = 1;
+    loop {
+        let modulus = low | (1 << N);
+        // Ben-Or's test as in is_irreducible
+        let mut frobenius = 0b10;
+        let mut irreducible = true;
+        let mut k = 1;
+        while irreducible && k <= N / 2 {
+            frobenius = binary_mul(frobenius, frobenius, modulus, N);
+            irreducible = binary_is_coprime(modulus, frobenius ^ 0b10);
+            k += 1;
+        }
+        if irreducible {
+            let mut coefficients = [0; N];
+            let mut i = 0;
+            while i < N {
+                coefficients[i] = ((low >> i) & 1) as u64;
+                i += 1;
+            }
+            return coefficients;
+        }
+        low += 2;
+    }
+}
+
+// The first irreducible modulus when ordered by largest coefficient and then
+// by the MODULUS encoding. Irreducibles with coefficients in {0, 1} are
+// plentiful, so the search stays short even for a large P, and for P = 2 it
+// is simply the smallest in the encoding.
+const fn default_modulus<const P: u64, const N: usize>() -> [u64; N] {
+    if P == 2 && N > 1 && N < 128 {
+        return binary_default_modulus::<N>();
+    }
+    let mut height = 0;
+    loop {
+        let mut modulus = [0; N];
+        loop {
+            let mut top = 0;
+            let mut i = 0;
+            while i < N {
+                if modulus[i] > top {
+                    top = modulus[i];
+                }
+                i += 1;
+            }
+            // x divides every candidate with c_0 = 0 apart from x itself
+            if top == height && (N == 1 || modulus[0] != 0) && is_irreducible::<P, N>(&modulus) {
+                return modulus;
+            }
+            let mut i = 0;
+            while i < N && modulus[i] == height {
+                modulus[i] = 0;
+                i += 1;
+            }
+            if i == N {
+                break;
+            }
+            modulus[i] += 1;
+        }
+        height += 1;
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> GF<P, N, MODULUS> {
+    // Low coefficients c_0, ..., c_{N-1} of the monic modulus
+    const POLYNOMIAL: [u64; N] = {
+        assert!(is_prime(P), "the characteristic P must be prime");
+        assert!(N > 0, "the degree N must be positive");
+        match MODULUS {
+            0 => default_modulus::<P, N>(),
+            _ => {
+                let mut modulus = [0; N];
+                let mut rest = MODULUS;
+                let mut i = 0;
+                while i < N {
+                    modulus[i] = (rest % P as u128) as u64;
+                    rest /= P as u128;
+                    i += 1;
+                }
+                assert!(rest == 0, "MODULUS must be below P^N");
+                assert!(is_irreducible::<P, N>(&modulus), "MODULUS must be irreducible");
+                modulus
+            }
+        }
+    };
+
+    pub fn new(coefficients: [u64; N]) -> Self {
+        let _ = Self::POLYNOMIAL;
+        Self(coefficients.map(|c| c % P))
+    }
+
+    pub fn coefficients(&self) -> [u64; N] {
+        self.0
+    }
+
+    // The element whose coefficients are the base-P digits of n, lowest first
+    pub fn from_integer(mut n: u128) -> Self {
+        let coefficients = std::array::from_fn(|_| {
+            let digit = (n % P as u128) as u64;
+            n /= P as u128;
+            digit
+        });
+        assert!(n == 0, "integer out of range for GF(P^N)");
+        Self::new(coefficients)
+    }
+
+    // Inverse of from_integer, or None when the value does not fit in a u128
+    pub fn to_integer(&self) -> Option<u128> {
+        self.0.iter().rev().try_fold(0u128, |n, &c| n.checked_mul(P as u128)?.checked_add(c as u128))
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        Self(poly_pow::<P, N>(&self.0, exp, &Self::POLYNOMIAL))
+    }
+
+    // The multiplicative inverse, or None for zero. With r = 1 + P + ... +
+    // P^(N-1), a^r is the norm of a and lies in GF(P), so
+    // a^-1 = a^(r-1) / a^r where a^(r-1) is the product of the conjugates
+    // a^P, ..., a^(P^(N-1)).
+    pub fn inverse(&self) -> Option<Self> {
+        let mut conjugate = *self;
+        let mut product = Self::one();
+        for _ in 1..N {
+            conjugate = conjugate.pow(P);
+            product = product * conjugate;
+        }
+        let norm = (*self * product).0[0];
+        match norm {
+            0 => None,
+            _ => Some(product * Self::from_integer(pow_mod(norm, P - 2, P) as u128))
+        }
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Add for GF<P, N, MODULUS> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| add_mod(self.0[i], rhs.0[i], P)))
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Sub for GF<P, N, MODULUS> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| sub_mod(self.0[i], rhs.0[i], P)))
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Mul for GF<P, N, MODULUS> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(poly_mul::<P, N>(&self.0, &rhs.0, &Self::POLYNOMIAL))
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Div for GF<P, N, MODULUS> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let inverse = rhs.inverse().expect("division by zero");
+        Self(poly_mul::<P, N>(&self.0, &inverse.0, &Self::POLYNOMIAL))
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Neg for GF<P, N, MODULUS> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|c| sub_mod(0, c, P)))
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Add<&GF<P, N, MODULUS>> for &GF<P, N, MODULUS> {
+    type Output = GF<P, N, MODULUS>;
+
+    fn add(self, rhs: &GF<P, N, MODULUS>) -> GF<P, N, MODULUS> {
+        *self + *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Sub<&GF<P, N, MODULUS>> for &GF<P, N, MODULUS> {
+    type Output = GF<P, N, MODULUS>;
+
+    fn sub(self, rhs: &GF<P, N, MODULUS>) -> GF<P, N, MODULUS> {
+        *self - *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Mul<&GF<P, N, MODULUS>> for &GF<P, N, MODULUS> {
+    type Output = GF<P, N, MODULUS>;
+
+    fn mul(self, rhs: &GF<P, N, MODULUS>) -> GF<P, N, MODULUS> {
+        *self * *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Div<&GF<P, N, MODULUS>> for &GF<P, N, MODULUS> {
+    type Output = GF<P, N, MODULUS>;
+
+    fn div(self, rhs: &GF<P, N, MODULUS>) -> GF<P, N, MODULUS> {
+        *self / *rhs
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Neg for &GF<P, N, MODULUS> {
+    type Output = GF<P, N, MODULUS>;
+
+    fn neg(self) -> GF<P, N, MODULUS> {
+        -*self
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> Zero for GF<P, N, MODULUS> {
+    fn zero() -> Self {
+        Self::new([0; N])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&c| c == 0)
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> One for GF<P, N, MODULUS> {
+    fn one() -> Self {
+        Self::from_integer(1)
+    }
+}
+
+impl<const P: u64, const N: usize, const MODULUS: u128> CommutativeRing for GF<P, N, MODULUS> {}
+impl<const P: u64, const N: usize, const MODULUS: u128> Field for GF<P, N, MODULUS> {}
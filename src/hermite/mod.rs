@@ -0,0 +1,68 @@
+use std::array::from_fn;
+
+use crate::{algebra::{EuclideanDomain, Ring}, matrix::Matrix};
+
+// target -= q * source
+pub(crate) fn sub_multiple<R, const N: usize>(target: &mut [R; N], source: &[R; N], q: &R)
+where
+    R: Ring
+{
+    for (x, y) in target.iter_mut().zip(source) {
+        *x = &*x - &(q * y);
+    }
+}
+
+pub(crate) fn identity<R: EuclideanDomain, const DIM: usize>() -> [[R; DIM]; DIM] {
+    from_fn(|i|
+        from_fn(|j| match i == j {
+            true => R::one(),
+            false => R::zero()
+        })
+    )
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS>
+where
+    R: EuclideanDomain
+{
+    // Row-style Hermite normal form: returns (H, U) with U unimodular and
+    // U A = H, where H is in echelon form with canonical pivots and the
+    // entries above each pivot reduced modulo it
+    pub fn hermite_normal_form(&self) -> (Self, Matrix<R, ROWS, ROWS>) {
+        let mut h = self.vals.clone();
+        let mut u = identity::<R, ROWS>();
+        let mut row = 0;
+        for col in 0..COLS {
+            if row == ROWS {
+                break;
+            }
+            // Euclid's algorithm on rows, leaving the gcd of the column in `row`
+            for k in row + 1..ROWS {
+                while !h[k][col].is_zero() {
+                    let (q, _) = h[row][col].div_rem(&h[k][col]);
+                    let (h_k, u_k) = (h[k].clone(), u[k].clone());
+                    sub_multiple(&mut h[row], &h_k, &q);
+                    sub_multiple(&mut u[row], &u_k, &q);
+                    h.swap(row, k);
+                    u.swap(row, k);
+                }
+            }
+            if h[row][col].is_zero() {
+                continue;
+            }
+
+            let unit = h[row][col].normalizing_unit();
+            h[row].iter_mut().for_each(|x| *x = &unit * &*x);
+            u[row].iter_mut().for_each(|x| *x = &unit * &*x);
+
+            let (h_row, u_row) = (h[row].clone(), u[row].clone());
+            for above in 0..row {
+                let (q, _) = h[above][col].div_rem(&h_row[col]);
+                sub_multiple(&mut h[above], &h_row, &q);
+                sub_multiple(&mut u[above], &u_row, &q);
+            }
+            row += 1;
+        }
+        (Matrix { vals: h }, Matrix { vals: u })
+    }
+}
@@ -0,0 +1,30 @@
+use std::{array::from_fn, ops::Mul};
+
+use crate::matrix::Matrix;
+
+// Block (i, j) of the result is a[i][j] * b, so the outer factor is `a`
+pub fn kronecker<'a, 'b, R, S, T, const M: usize, const N: usize, const P: usize, const Q: usize>(
+    a: &'a Matrix<R, M, N>,
+    b: &'b Matrix<S, P, Q>
+) -> Matrix<Matrix<T, P, Q>, M, N>
+where
+    &'a R: Mul<&'b S, Output = T>
+{
+    let vals = from_fn(|i|
+        from_fn(|j| b.scale_left(&a.vals[i][j]))
+    );
+    Matrix {
+        vals
+    }
+}
+
+// Flattened form of `kronecker`, with ROWS = M * P and COLS = N * Q
+pub fn kronecker_flat<'a, 'b, R, S, T, const M: usize, const N: usize, const P: usize, const Q: usize, const ROWS: usize, const COLS: usize>(
+    a: &'a Matrix<R, M, N>,
+    b: &'b Matrix<S, P, Q>
+) -> Matrix<T, ROWS, COLS>
+where
+    &'a R: Mul<&'b S, Output = T>
+{
+    kronecker(a, b).flatten()
+}
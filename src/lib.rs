@@ -1,7 +1,34 @@
+#![feature(trait_alias)]
+
+mod macros;
 mod matrix;
 pub mod ops;
+mod determinant;
+mod nested;
+mod kronecker;
+mod block;
+mod submatrix;
+mod elimination;
+mod lu;
+mod algebra;
+mod hermite;
+mod smith;
+mod semiring;
+mod zmod;
+mod gf;
+mod poly;
 
 #[cfg(test)]
 mod tests;
 
-pub use matrix::{Matrix, MatrixRef};
+pub use matrix::{Matrix, MatrixMut, MatrixRef, TransposedRef};
+pub use ops::Scalar;
+pub use kronecker::{kronecker, kronecker_flat};
+pub use block::{direct_sum, hstack, vstack};
+pub use submatrix::SubmatrixRef;
+pub use lu::{LuDecomposition, Singular};
+pub use algebra::{AdditiveGroup, AdditiveMonoid, CommutativeRing, DivisionRing, EuclideanDomain, Field, Ring, Semiring};
+pub use semiring::{Boolean, MaxPlus, MinPlus};
+pub use zmod::Zmod;
+pub use gf::GF;
+pub use poly::Poly;
@@ -0,0 +1,140 @@
+use std::{array::from_fn, error::Error, fmt::{self, Display}};
+use num_traits::Float;
+
+use crate::matrix::Matrix;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Singular;
+
+impl Display for Singular {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "matrix is singular to working precision")
+    }
+}
+
+impl Error for Singular {}
+
+// P A = L U, where row i of P A is row permutation[i] of A, L is unit lower
+// triangular and U is upper triangular
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LuDecomposition<T, const DIM: usize> {
+    l: Matrix<T, DIM, DIM>,
+    u: Matrix<T, DIM, DIM>,
+    permutation: [usize; DIM],
+    even: bool
+}
+
+impl<T: Float, const DIM: usize> Matrix<T, DIM, DIM> {
+    pub fn lu(&self) -> Result<LuDecomposition<T, DIM>, Singular> {
+        let mut u = self.vals;
+        let mut l = [[T::zero(); DIM]; DIM];
+        let mut permutation = from_fn(|i| i);
+        let mut even = true;
+
+        if !u.iter().flatten().all(|x| x.is_finite()) {
+            return Err(Singular);
+        }
+        // A pivot is treated as zero only when it is negligible next to the
+        // largest entry of both its original row and its own column, so
+        // neither badly scaled rows nor badly scaled columns are penalised
+        let size = T::epsilon() * T::from(DIM).unwrap();
+        let mut row_tolerance = u.map(|row|
+            size * row.iter().fold(T::zero(), |x, y| x.max(y.abs()))
+        );
+        let col_tolerance: [T; DIM] = from_fn(|j|
+            size * u.iter().fold(T::zero(), |x, row| x.max(row[j].abs()))
+        );
+
+        for col in 0..DIM {
+            let pivot = (col..DIM)
+                .max_by(|&a, &b| u[a][col].abs().partial_cmp(&u[b][col].abs()).unwrap())
+                .unwrap();
+            if u[pivot][col].abs() <= row_tolerance[pivot].min(col_tolerance[col]) {
+                return Err(Singular);
+            }
+            if pivot != col {
+                u.swap(pivot, col);
+                l.swap(pivot, col);
+                row_tolerance.swap(pivot, col);
+                permutation.swap(pivot, col);
+                even = !even;
+            }
+            let pivot_row = u[col];
+            for row in col + 1..DIM {
+                let factor = u[row][col] / pivot_row[col];
+                l[row][col] = factor;
+                for (x, p) in u[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                    *x = *x - factor * *p;
+                }
+            }
+        }
+        for (i, row) in l.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+
+        Ok(LuDecomposition {
+            l: Matrix { vals: l },
+            u: Matrix { vals: u },
+            permutation,
+            even
+        })
+    }
+}
+
+impl<T: Float, const DIM: usize> LuDecomposition<T, DIM> {
+    pub fn l(&self) -> &Matrix<T, DIM, DIM> {
+        &self.l
+    }
+
+    pub fn u(&self) -> &Matrix<T, DIM, DIM> {
+        &self.u
+    }
+
+    pub fn permutation(&self) -> &[usize; DIM] {
+        &self.permutation
+    }
+
+    pub fn determinant(&self) -> T {
+        let det = (0..DIM).fold(T::one(), |x, i| x * self.u.vals[i][i]);
+        match self.even {
+            true => det,
+            false => -det
+        }
+    }
+
+    pub fn solve<const K: usize>(&self, b: &Matrix<T, DIM, K>) -> Matrix<T, DIM, K> {
+        let (l, u) = (&self.l.vals, &self.u.vals);
+        let mut x: [[T; K]; DIM] = from_fn(|i| b.vals[self.permutation[i]]);
+        // Forward substitution with L, then back substitution with U
+        for i in 0..DIM {
+            for j in 0..i {
+                let done = x[j];
+                for (y, d) in x[i].iter_mut().zip(done) {
+                    *y = *y - l[i][j] * d;
+                }
+            }
+        }
+        for i in (0..DIM).rev() {
+            for j in i + 1..DIM {
+                let done = x[j];
+                for (y, d) in x[i].iter_mut().zip(done) {
+                    *y = *y - u[i][j] * d;
+                }
+            }
+            x[i].iter_mut().for_each(|y| *y = *y / u[i][i]);
+        }
+        Matrix {
+            vals: x
+        }
+    }
+
+    pub fn inverse(&self) -> Matrix<T, DIM, DIM> {
+        let identity = from_fn(|i|
+            from_fn(|j| match i == j {
+                true => T::one(),
+                false => T::zero()
+            })
+        );
+        self.solve(&Matrix { vals: identity })
+    }
+}
@@ -0,0 +1,72 @@
+/// Builds a [`Matrix`](crate::Matrix) row by row, with rows separated by `;`.
+///
+/// ```
+/// use matrix_rings::{matrix, Matrix};
+///
+/// let a: Matrix<i32, 2, 3> = matrix![1, 2, 3; 4, 5, 6];
+/// assert_eq!(a, Matrix::new([[1, 2, 3], [4, 5, 6]]));
+/// ```
+///
+/// Rows of different lengths are rejected at compile time:
+///
+/// ```compile_fail
+/// use matrix_rings::matrix;
+///
+/// let a = matrix![1, 2; 3];
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {{
+        $crate::__matrix_check_rows!($([$($x),+])+);
+        $crate::Matrix::new([$([$($x),+]),+])
+    }};
+}
+
+/// Builds a [`MatrixRef`](crate::MatrixRef) borrowing a temporary array, with
+/// the same syntax as [`matrix!`].
+///
+/// ```
+/// use matrix_rings::{matrix, matrix_ref, MatrixRef};
+///
+/// let a: MatrixRef<i32, 2, 2> = matrix_ref![1, 2; 3, 4];
+/// assert_eq!(a[(1, 0)], 3);
+///
+/// let x = 5;
+/// assert_eq!(a * matrix_ref![x, 0; 0, x], matrix![5, 10; 15, 20]);
+/// ```
+///
+/// The array lives until the end of the enclosing statement, so a `let`
+/// binding only outlives it when every entry is a constant. Otherwise build
+/// the matrix with [`matrix!`] and borrow it with `as_ref`:
+///
+/// ```compile_fail
+/// use matrix_rings::matrix_ref;
+///
+/// let x = 5;
+/// let a = matrix_ref![x, 0; 0, x];
+/// assert_eq!(a[(0, 0)], 5);
+/// ```
+#[macro_export]
+macro_rules! matrix_ref {
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {
+        $crate::MatrixRef::new((
+            &[$([$($x),+]),+],
+            { $crate::__matrix_check_rows!($([$($x),+])+); }
+        ).0)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __matrix_check_rows {
+    ($([$($x:expr),+])+) => {
+        const _: () = {
+            let lengths = [$([$(stringify!($x)),+].len()),+];
+            let mut i = 1;
+            while i < lengths.len() {
+                assert!(lengths[i] == lengths[0], "matrix rows must all have the same number of entries");
+                i += 1;
+            }
+        };
+    };
+}
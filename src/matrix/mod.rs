@@ -1,6 +1,8 @@
-use std::{fmt::Debug, ops::{Add, Index}};
+use std::{array::from_fn, fmt::Debug, ops::{Add, Index, IndexMut}};
 
-use num_traits::Zero;
+use num_traits::{One, Zero};
+
+use crate::algebra::Semiring;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Matrix<R, const ROWS: usize, const COLS: usize> {
@@ -12,6 +14,17 @@ pub struct MatrixRef<'a, R, const ROWS: usize, const COLS: usize> {
     pub(crate) vals: &'a [[R;COLS];ROWS]
 }
 
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct MatrixMut<'a, R, const ROWS: usize, const COLS: usize> {
+    pub(crate) vals: &'a mut [[R;COLS];ROWS]
+}
+
+// A ROWS x COLS view of a borrowed COLS x ROWS array, indexing (i, j) as (j, i)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TransposedRef<'a, R, const ROWS: usize, const COLS: usize> {
+    pub(crate) vals: &'a [[R;ROWS];COLS]
+}
+
 impl<R, const ROWS: usize, const COLS: usize> Matrix<R,ROWS,COLS> {
     pub fn new(vals: [[R;COLS];ROWS]) -> Self {
         Self {
@@ -24,6 +37,36 @@ impl<R, const ROWS: usize, const COLS: usize> Matrix<R,ROWS,COLS> {
             vals: &self.vals
         }
     }
+
+    pub fn as_mut<'a>(&'a mut self) -> MatrixMut<'a, R, ROWS, COLS> {
+        MatrixMut {
+            vals: &mut self.vals
+        }
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&R> {
+        self.vals.get(row)?.get(col)
+    }
+
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut R> {
+        self.vals.get_mut(row)?.get_mut(col)
+    }
+
+    pub fn transpose(self) -> Matrix<R, COLS, ROWS> {
+        let mut rows = self.vals.map(|row| row.into_iter());
+        let vals = from_fn(|_|
+            from_fn(|j| rows[j].next().unwrap())
+        );
+        Matrix {
+            vals
+        }
+    }
+
+    pub fn transposed_view<'a>(&'a self) -> TransposedRef<'a, R, COLS, ROWS> {
+        TransposedRef {
+            vals: &self.vals
+        }
+    }
 }
 
 impl<'a, R, const ROWS: usize, const COLS: usize> MatrixRef<'a, R, ROWS, COLS> {
@@ -41,6 +84,84 @@ impl<'a, R, const ROWS: usize, const COLS: usize> MatrixRef<'a, R, ROWS, COLS> {
             vals: self.vals.clone()
         }
     }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&'a R> {
+        self.vals.get(row)?.get(col)
+    }
+
+    pub fn transpose(&self) -> Matrix<R, COLS, ROWS>
+    where
+        R: Clone
+    {
+        self.transposed_view().to_matrix()
+    }
+
+    pub fn transposed_view(&self) -> TransposedRef<'a, R, COLS, ROWS> {
+        TransposedRef {
+            vals: self.vals
+        }
+    }
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> MatrixMut<'a, R, ROWS, COLS> {
+    pub fn new(vals: &'a mut [[R;COLS];ROWS]) -> Self {
+        Self {
+            vals
+        }
+    }
+
+    pub fn as_ref(&self) -> MatrixRef<'_, R, ROWS, COLS> {
+        MatrixRef {
+            vals: self.vals
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<R,ROWS,COLS>
+    where
+        R: Clone
+    {
+        Matrix {
+            vals: self.vals.clone()
+        }
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&R> {
+        self.vals.get(row)?.get(col)
+    }
+
+    pub fn get_mut(&mut self, (row, col): (usize, usize)) -> Option<&mut R> {
+        self.vals.get_mut(row)?.get_mut(col)
+    }
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> TransposedRef<'a, R, ROWS, COLS> {
+    pub fn new(vals: &'a [[R;ROWS];COLS]) -> Self {
+        Self {
+            vals
+        }
+    }
+
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&'a R> {
+        self.vals.get(col)?.get(row)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<R,ROWS,COLS>
+    where
+        R: Clone
+    {
+        let vals = from_fn(|i|
+            from_fn(|j| self.vals[j][i].clone())
+        );
+        Matrix {
+            vals
+        }
+    }
+
+    pub fn transposed_view(&self) -> MatrixRef<'a, R, COLS, ROWS> {
+        MatrixRef {
+            vals: self.vals
+        }
+    }
 }
 
 impl<R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matrix<R, ROWS, COLS> {
@@ -51,6 +172,12 @@ impl<R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matrix<R
     }
 }
 
+impl<R, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)> for Matrix<R, ROWS, COLS> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.vals[row][col]
+    }
+}
+
 impl<'a, R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for MatrixRef<'a, R, ROWS, COLS> {
     type Output = R;
 
@@ -59,6 +186,28 @@ impl<'a, R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for Matr
     }
 }
 
+impl<'a, R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for MatrixMut<'a, R, ROWS, COLS> {
+    type Output = R;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.vals[row][col]
+    }
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> IndexMut<(usize, usize)> for MatrixMut<'a, R, ROWS, COLS> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.vals[row][col]
+    }
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for TransposedRef<'a, R, ROWS, COLS> {
+    type Output = R;
+
+    fn index(&self, (row, col): (usize, usize)) -> &'a R {
+        &self.vals[col][row]
+    }
+}
+
 impl<R,const DIM: usize> Matrix<R,DIM,DIM> {
     pub fn trace(&self) -> R
     where 
@@ -69,4 +218,24 @@ impl<R,const DIM: usize> Matrix<R,DIM,DIM> {
         .map(|i|&self.vals[i][i])
         .fold(R::zero(),|x,y|&x + y)
     }
-}
\ No newline at end of file
+
+    // Evaluates c_0 I + c_1 A + ... + c_n A^n, with coefficients in ascending order
+    pub fn evaluate_polynomial(&self, coefficients: &[R]) -> Self
+    where
+        R: Semiring
+    {
+        let mut result = Self::zero();
+        let mut power = Self::one();
+        for (k, c) in coefficients.iter().enumerate() {
+            result = Self {
+                vals: from_fn(|i|
+                    from_fn(|j| &result.vals[i][j] + &(c * &power.vals[i][j]))
+                )
+            };
+            if k + 1 < coefficients.len() {
+                power = &power * self;
+            }
+        }
+        result
+    }
+}
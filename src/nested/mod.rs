@@ -0,0 +1,85 @@
+use std::array::from_fn;
+
+use crate::{algebra::AdditiveMonoid, matrix::Matrix};
+
+// The crate builds with the next trait solver, which rules out
+// `generic_const_exprs`, so shapes like `{M * P}` are taken as extra const
+// parameters (usually inferred from the expected type) and checked at compile time.
+
+impl<R, const P: usize, const Q: usize, const M: usize, const N: usize> Matrix<Matrix<R, P, Q>, M, N> {
+    pub fn flatten<const ROWS: usize, const COLS: usize>(self) -> Matrix<R, ROWS, COLS> {
+        const {
+            assert!(ROWS == M * P, "flattened row count must be M * P");
+            assert!(COLS == N * Q, "flattened column count must be N * Q");
+        }
+        let mut blocks = self.vals.map(|row|
+            row.map(|block| block.vals.map(|inner| inner.into_iter()))
+        );
+        // from_fn fills in ascending order, so every inner row is consumed left to right
+        let vals = from_fn(|i|
+            from_fn(|j| blocks[i / P][j / Q][i % P].next().unwrap())
+        );
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS> {
+    pub fn unflatten<const P: usize, const Q: usize, const M: usize, const N: usize>(self) -> Matrix<Matrix<R, P, Q>, M, N> {
+        const {
+            assert!(ROWS == M * P, "row count must be M * P");
+            assert!(COLS == N * Q, "column count must be N * Q");
+        }
+        let mut rows = self.vals.map(|row| row.into_iter());
+        let vals = from_fn(|i|
+            from_fn(|_|
+                Matrix {
+                    vals: from_fn(|k|
+                        from_fn(|_| rows[i * P + k].next().unwrap())
+                    )
+                }
+            )
+        );
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<R, const P: usize, const Q: usize, const M: usize, const N: usize> Matrix<Matrix<R, P, Q>, M, N> {
+    // Transposes the outer level only, leaving each block as it is
+    pub fn partial_transpose_outer(self) -> Matrix<Matrix<R, P, Q>, N, M> {
+        self.transpose()
+    }
+
+    // Transposes each block in place, leaving the outer layout as it is
+    pub fn partial_transpose_inner(self) -> Matrix<Matrix<R, Q, P>, M, N> {
+        let vals = self.vals.map(|row|
+            row.map(|block| block.transpose())
+        );
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<R, const P: usize, const N: usize> Matrix<Matrix<R, P, P>, N, N>
+where
+    R: AdditiveMonoid
+{
+    // Traces out the outer factor: the sum of the diagonal blocks
+    pub fn partial_trace_outer(&self) -> Matrix<R, P, P> {
+        self.trace()
+    }
+
+    // Traces out the inner factor: the trace of every block
+    pub fn partial_trace_inner(&self) -> Matrix<R, N, N> {
+        let vals = from_fn(|i|
+            from_fn(|j| self.vals[i][j].trace())
+        );
+        Matrix {
+            vals
+        }
+    }
+}
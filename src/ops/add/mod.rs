@@ -1,15 +1,15 @@
-use std::{array::from_fn, ops::{Add, Mul}};
+use std::{array::from_fn, ops::{Add, AddAssign}};
 use num_traits::{One, Zero};
 
-use crate::matrix::{Matrix, MatrixRef};
+use crate::{algebra::{AdditiveMonoid, Semiring}, matrix::{Matrix, MatrixRef, TransposedRef}};
 
-impl<'a, 'b, R, const ROWS: usize, const COLS: usize> Add<MatrixRef<'b, R, ROWS, COLS>> for MatrixRef<'a, R, ROWS, COLS>
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<MatrixRef<'b, R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
 where
-    &'a R: Add<&'b R, Output = R>
+    &'a R1: Add<&'b R2, Output = R3>
 {
-    type Output = Matrix<R, ROWS, COLS>;
+    type Output = Matrix<R3, ROWS, COLS>;
 
-    fn add(self, rhs: MatrixRef<'b, R, ROWS, COLS>) -> Self::Output {
+    fn add(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
         let MatrixRef { vals: lhs } = self;
         let MatrixRef { vals: rhs } = rhs;
         let vals = from_fn(|i|
@@ -20,32 +20,177 @@ where
     }
 }
 
-impl<'a, 'b, R, const ROWS: usize, const COLS: usize> Add<&'b Matrix<R, ROWS, COLS>> for &'a Matrix<R, ROWS, COLS>
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<&'b Matrix<R2, ROWS, COLS>> for &'a Matrix<R1, ROWS, COLS>
 where 
-    &'a R: Add<&'b R, Output = R>
+    &'a R1: Add<&'b R2, Output = R3>
 {
-    type Output = Matrix<R, ROWS, COLS>;
+    type Output = Matrix<R3, ROWS, COLS>;
 
-    fn add(self, rhs: &'b Matrix<R, ROWS, COLS>) -> Self::Output {
+    fn add(self, rhs: &'b Matrix<R2, ROWS, COLS>) -> Self::Output {
         self.as_ref() + rhs.as_ref()
     }
 }
 
-impl <R, const ROWS: usize, const COLS: usize> Add for Matrix<R, ROWS, COLS>
+impl<R1, R2, R3, const ROWS: usize, const COLS: usize> Add<Matrix<R2, ROWS, COLS>> for Matrix<R1, ROWS, COLS>
 where
-    for<'a, 'b> &'a R: Add<&'b R, Output = R>
+    for<'a, 'b> &'a R1: Add<&'b R2, Output = R3>
 {
-    type Output = Self;
+    type Output = Matrix<R3, ROWS, COLS>;
 
-    fn add(self, rhs: Self) -> Self::Output {
+    fn add(self, rhs: Matrix<R2, ROWS, COLS>) -> Self::Output {
         &self + &rhs
     }
 }
 
+impl<'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<MatrixRef<'b, R2, ROWS, COLS>> for Matrix<R1, ROWS, COLS>
+where
+    for<'a> &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() + rhs
+    }
+}
+
+impl<'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<&'b Matrix<R2, ROWS, COLS>> for Matrix<R1, ROWS, COLS>
+where
+    for<'a> &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: &'b Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() + rhs.as_ref()
+    }
+}
+
+impl<'a, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<Matrix<R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
+where
+    for<'b> &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self + rhs.as_ref()
+    }
+}
+
+impl<'a, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<Matrix<R2, ROWS, COLS>> for &'a Matrix<R1, ROWS, COLS>
+where
+    for<'b> &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() + rhs.as_ref()
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<&'b Matrix<R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: &'b Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self + rhs.as_ref()
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<MatrixRef<'b, R2, ROWS, COLS>> for &'a Matrix<R1, ROWS, COLS>
+where
+    &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() + rhs
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<TransposedRef<'b, R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: TransposedRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        let vals = from_fn(|i|
+        from_fn(|j| &self.vals[i][j] + &rhs.vals[j][i]));
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<MatrixRef<'b, R2, ROWS, COLS>> for TransposedRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        let vals = from_fn(|i|
+        from_fn(|j| &self.vals[j][i] + &rhs.vals[i][j]));
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Add<TransposedRef<'b, R2, ROWS, COLS>> for TransposedRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Add<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn add(self, rhs: TransposedRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        let vals = from_fn(|i|
+        from_fn(|j| &self.vals[j][i] + &rhs.vals[j][i]));
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<'b, R, const ROWS: usize, const COLS: usize> AddAssign<MatrixRef<'b, R, ROWS, COLS>> for Matrix<R, ROWS, COLS>
+where
+    R: AddAssign<&'b R>
+{
+    fn add_assign(&mut self, rhs: MatrixRef<'b, R, ROWS, COLS>) {
+        for (row, rhs_row) in self.vals.iter_mut().zip(rhs.vals) {
+            for (x, y) in row.iter_mut().zip(rhs_row) {
+                *x += y;
+            }
+        }
+    }
+}
+
+impl<'b, R, const ROWS: usize, const COLS: usize> AddAssign<&'b Matrix<R, ROWS, COLS>> for Matrix<R, ROWS, COLS>
+where
+    R: AddAssign<&'b R>
+{
+    fn add_assign(&mut self, rhs: &'b Matrix<R, ROWS, COLS>) {
+        *self += rhs.as_ref();
+    }
+}
+
+impl<R, const ROWS: usize, const COLS: usize> AddAssign for Matrix<R, ROWS, COLS>
+where
+    R: AddAssign
+{
+    fn add_assign(&mut self, rhs: Self) {
+        for (row, rhs_row) in self.vals.iter_mut().zip(rhs.vals) {
+            for (x, y) in row.iter_mut().zip(rhs_row) {
+                *x += y;
+            }
+        }
+    }
+}
+
 impl<R, const ROWS: usize, const COLS: usize> Zero for Matrix<R, ROWS, COLS>
 where
-    for<'a, 'b> &'a R: Add<&'b R, Output = R> + PartialEq<&'b R>,
-    R: Zero
+    R: AdditiveMonoid
 {
     fn zero() -> Self {
         let vals = from_fn(|_|
@@ -57,14 +202,13 @@ where
     }
 
     fn is_zero(&self) -> bool {
-        self.vals.iter().all(|x|x.iter().all(|y|y == &R::zero()))
+        self.vals.iter().all(|x|x.iter().all(R::is_zero))
     }
 }
 
 impl<R, const DIM: usize> One for Matrix<R, DIM, DIM>
 where
-    for<'a, 'b> &'a R: Mul<&'b R, Output = R> + PartialEq<&'b R>,
-    R: One + Zero
+    R: Semiring
 {
     fn one() -> Self {
         let vals = from_fn(|i|
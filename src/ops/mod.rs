@@ -1,4 +1,14 @@
+// The binary operators take separate element types, R1 op R2 -> R3, which
+// the single-type algebra traits cannot describe, so they keep explicit
+// reference bounds. Owned operands are borrowed rather than reused, since an
+// R1 buffer cannot hold R3 entries. AddAssign and SubAssign update entries in
+// place through the element's own operator; Zero, One and MulAssign use the
+// traits.
 mod add;
 mod mul;
 mod sub;
-mod neg;
\ No newline at end of file
+mod neg;
+mod pow;
+mod scalar;
+
+pub use scalar::Scalar;
\ No newline at end of file
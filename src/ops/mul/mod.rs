@@ -1,7 +1,7 @@
-use std::{array::from_fn, ops::{Mul, Add}};
+use std::{array::from_fn, ops::{Mul, MulAssign, Add}};
 use num_traits::Zero;
 
-use crate::matrix::{Matrix, MatrixRef};
+use crate::{algebra::Semiring, matrix::{Matrix, MatrixRef, TransposedRef}};
 
 // Multiplication implementations will go here
 
@@ -51,4 +51,169 @@ where
     fn mul(self, rhs: Matrix<R2, B, C>) -> Self::Output {
         &self * &rhs
     }
-}
\ No newline at end of file
+}
+
+impl<'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<MatrixRef<'b, R2, B, C>> for Matrix<R1, A, B>
+where
+    for<'a> &'a R1: Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: MatrixRef<'b, R2, B, C>) -> Self::Output {
+        self.as_ref() * rhs
+    }
+}
+
+impl<'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<&'b Matrix<R2, B, C>> for Matrix<R1, A, B>
+where
+    for<'a> &'a R1: Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: &'b Matrix<R2, B, C>) -> Self::Output {
+        self.as_ref() * rhs.as_ref()
+    }
+}
+
+impl<'a, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<Matrix<R2, B, C>> for MatrixRef<'a, R1, A, B>
+where
+    for<'b> &'a R1: Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: Matrix<R2, B, C>) -> Self::Output {
+        self * rhs.as_ref()
+    }
+}
+
+impl<'a, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<Matrix<R2, B, C>> for &'a Matrix<R1, A, B>
+where
+    for<'b> &'a R1: Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: Matrix<R2, B, C>) -> Self::Output {
+        self.as_ref() * rhs.as_ref()
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<&'b Matrix<R2, B, C>> for MatrixRef<'a, R1, A, B>
+where
+    &'a R1: Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: &'b Matrix<R2, B, C>) -> Self::Output {
+        self * rhs.as_ref()
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<MatrixRef<'b, R2, B, C>> for &'a Matrix<R1, A, B>
+where
+    &'a R1: Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: MatrixRef<'b, R2, B, C>) -> Self::Output {
+        self.as_ref() * rhs
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<TransposedRef<'b, R2, B, C>> for MatrixRef<'a, R1, A, B>
+where 
+    &'a R1 : Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: TransposedRef<'b, R2, B, C>) -> Self::Output {
+        let vals = from_fn(|i|
+            from_fn(|k|
+                (0..B)
+                .map(|j| &self.vals[i][j] * &rhs.vals[k][j]
+                )
+                .fold(R3::zero(), |x, y| x + y)
+            )
+        );
+        Self::Output {
+            vals
+        }
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<MatrixRef<'b, R2, B, C>> for TransposedRef<'a, R1, A, B>
+where 
+    &'a R1 : Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: MatrixRef<'b, R2, B, C>) -> Self::Output {
+        let vals = from_fn(|i|
+            from_fn(|k|
+                (0..B)
+                .map(|j| &self.vals[j][i] * &rhs.vals[j][k]
+                )
+                .fold(R3::zero(), |x, y| x + y)
+            )
+        );
+        Self::Output {
+            vals
+        }
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const A: usize, const B: usize, const C: usize> Mul<TransposedRef<'b, R2, B, C>> for TransposedRef<'a, R1, A, B>
+where 
+    &'a R1 : Mul<&'b R2, Output = R3>,
+    R3: Add<Output = R3> + Zero
+{
+    type Output = Matrix<R3, A, C>;
+
+    fn mul(self, rhs: TransposedRef<'b, R2, B, C>) -> Self::Output {
+        let vals = from_fn(|i|
+            from_fn(|k|
+                (0..B)
+                .map(|j| &self.vals[j][i] * &rhs.vals[k][j]
+                )
+                .fold(R3::zero(), |x, y| x + y)
+            )
+        );
+        Self::Output {
+            vals
+        }
+    }
+}
+
+impl<'b, R, const DIM: usize> MulAssign<MatrixRef<'b, R, DIM, DIM>> for Matrix<R, DIM, DIM>
+where
+    R: Semiring
+{
+    fn mul_assign(&mut self, rhs: MatrixRef<'b, R, DIM, DIM>) {
+        *self = self.as_ref() * rhs;
+    }
+}
+
+impl<'b, R, const DIM: usize> MulAssign<&'b Matrix<R, DIM, DIM>> for Matrix<R, DIM, DIM>
+where
+    R: Semiring
+{
+    fn mul_assign(&mut self, rhs: &'b Matrix<R, DIM, DIM>) {
+        *self *= rhs.as_ref();
+    }
+}
+
+impl<R, const DIM: usize> MulAssign for Matrix<R, DIM, DIM>
+where
+    R: Semiring
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
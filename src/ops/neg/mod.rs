@@ -1,6 +1,6 @@
 use std::{array::from_fn, ops::Neg};
 
-use crate::{Matrix, MatrixRef};
+use crate::{Matrix, MatrixRef, TransposedRef};
 
 impl<'a, R,const ROWS: usize, const COLS: usize> Neg for MatrixRef<'a,R,ROWS,COLS> 
 where 
@@ -20,6 +20,24 @@ where
     }
 }
 
+impl<'a, R,const ROWS: usize, const COLS: usize> Neg for TransposedRef<'a,R,ROWS,COLS> 
+where 
+    &'a R: Neg<Output = R>
+{
+    type Output = Matrix<R,ROWS,COLS>;
+
+    fn neg(self) -> Self::Output {
+        let vals = from_fn(|i|
+            from_fn(|j| 
+                -&self.vals[j][i]
+            )
+        );
+        Self::Output {
+            vals
+        }
+    }
+}
+
 impl<R,const ROWS: usize, const COLS: usize> Neg for &Matrix<R,ROWS,COLS> 
 where 
     for<'a> &'a R: Neg<Output = R>
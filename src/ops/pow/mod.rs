@@ -0,0 +1,76 @@
+use num_traits::{One, Pow};
+
+use crate::{algebra::Semiring, matrix::Matrix};
+
+impl<R, const DIM: usize> Matrix<R, DIM, DIM>
+where
+    R: Semiring
+{
+    pub fn pow(self, exp: u64) -> Self {
+        self.pow_reduced(exp, |x| x)
+    }
+
+    // Binary exponentiation where `reduce` is applied to every entry of
+    // every intermediate product, e.g. `|x| x % m` for powers modulo m
+    pub fn pow_reduced(self, mut exp: u64, reduce: impl Fn(R) -> R) -> Self {
+        let reduce_all = |m: Self| Self {
+            vals: m.vals.map(|row| row.map(&reduce))
+        };
+        let mut result = Self::one();
+        let mut base = reduce_all(self);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = reduce_all(&result * &base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = reduce_all(&base * &base);
+            }
+        }
+        result
+    }
+}
+
+impl<R, const DIM: usize> Pow<u64> for &Matrix<R, DIM, DIM>
+where
+    R: Semiring + Clone
+{
+    type Output = Matrix<R, DIM, DIM>;
+
+    fn pow(self, rhs: u64) -> Self::Output {
+        Matrix::pow(self.clone(), rhs)
+    }
+}
+
+impl<R, const DIM: usize> Pow<u32> for &Matrix<R, DIM, DIM>
+where
+    R: Semiring + Clone
+{
+    type Output = Matrix<R, DIM, DIM>;
+
+    fn pow(self, rhs: u32) -> Self::Output {
+        Matrix::pow(self.clone(), rhs as u64)
+    }
+}
+
+impl<R, const DIM: usize> Pow<u64> for Matrix<R, DIM, DIM>
+where
+    R: Semiring
+{
+    type Output = Self;
+
+    fn pow(self, rhs: u64) -> Self::Output {
+        Matrix::pow(self, rhs)
+    }
+}
+
+impl<R, const DIM: usize> Pow<u32> for Matrix<R, DIM, DIM>
+where
+    R: Semiring
+{
+    type Output = Self;
+
+    fn pow(self, rhs: u32) -> Self::Output {
+        Matrix::pow(self, rhs as u64)
+    }
+}
@@ -0,0 +1,116 @@
+use std::{array::from_fn, ops::Mul};
+
+use crate::matrix::{Matrix, MatrixRef};
+
+// Wrapper marking a ring element as a scalar, so `Scalar(r) * m` multiplies
+// every entry on the left and `m * Scalar(r)` every entry on the right
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Scalar<R>(pub R);
+
+impl<'a, R, const ROWS: usize, const COLS: usize> MatrixRef<'a, R, ROWS, COLS> {
+    pub fn scale_left<'s, S, T>(&self, scalar: &'s S) -> Matrix<T, ROWS, COLS>
+    where
+        &'s S: Mul<&'a R, Output = T>
+    {
+        let vals = from_fn(|i|
+            from_fn(|j| scalar * &self.vals[i][j])
+        );
+        Matrix {
+            vals
+        }
+    }
+
+    pub fn scale_right<'s, S, T>(&self, scalar: &'s S) -> Matrix<T, ROWS, COLS>
+    where
+        &'a R: Mul<&'s S, Output = T>
+    {
+        let vals = from_fn(|i|
+            from_fn(|j| &self.vals[i][j] * scalar)
+        );
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS> {
+    pub fn scale_left<'a, 's, S, T>(&'a self, scalar: &'s S) -> Matrix<T, ROWS, COLS>
+    where
+        &'s S: Mul<&'a R, Output = T>
+    {
+        self.as_ref().scale_left(scalar)
+    }
+
+    pub fn scale_right<'a, 's, S, T>(&'a self, scalar: &'s S) -> Matrix<T, ROWS, COLS>
+    where
+        &'a R: Mul<&'s S, Output = T>
+    {
+        self.as_ref().scale_right(scalar)
+    }
+}
+
+impl<'a, S, R, T, const ROWS: usize, const COLS: usize> Mul<MatrixRef<'a, R, ROWS, COLS>> for Scalar<S>
+where
+    for<'s> &'s S: Mul<&'a R, Output = T>
+{
+    type Output = Matrix<T, ROWS, COLS>;
+
+    fn mul(self, rhs: MatrixRef<'a, R, ROWS, COLS>) -> Self::Output {
+        rhs.scale_left(&self.0)
+    }
+}
+
+impl<'a, S, R, T, const ROWS: usize, const COLS: usize> Mul<&'a Matrix<R, ROWS, COLS>> for Scalar<S>
+where
+    for<'s> &'s S: Mul<&'a R, Output = T>
+{
+    type Output = Matrix<T, ROWS, COLS>;
+
+    fn mul(self, rhs: &'a Matrix<R, ROWS, COLS>) -> Self::Output {
+        self * rhs.as_ref()
+    }
+}
+
+impl<S, R, T, const ROWS: usize, const COLS: usize> Mul<Matrix<R, ROWS, COLS>> for Scalar<S>
+where
+    for<'s, 'a> &'s S: Mul<&'a R, Output = T>
+{
+    type Output = Matrix<T, ROWS, COLS>;
+
+    fn mul(self, rhs: Matrix<R, ROWS, COLS>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<'a, S, R, T, const ROWS: usize, const COLS: usize> Mul<Scalar<S>> for MatrixRef<'a, R, ROWS, COLS>
+where
+    for<'s> &'a R: Mul<&'s S, Output = T>
+{
+    type Output = Matrix<T, ROWS, COLS>;
+
+    fn mul(self, rhs: Scalar<S>) -> Self::Output {
+        self.scale_right(&rhs.0)
+    }
+}
+
+impl<'a, S, R, T, const ROWS: usize, const COLS: usize> Mul<Scalar<S>> for &'a Matrix<R, ROWS, COLS>
+where
+    for<'s> &'a R: Mul<&'s S, Output = T>
+{
+    type Output = Matrix<T, ROWS, COLS>;
+
+    fn mul(self, rhs: Scalar<S>) -> Self::Output {
+        self.as_ref() * rhs
+    }
+}
+
+impl<S, R, T, const ROWS: usize, const COLS: usize> Mul<Scalar<S>> for Matrix<R, ROWS, COLS>
+where
+    for<'a, 's> &'a R: Mul<&'s S, Output = T>
+{
+    type Output = Matrix<T, ROWS, COLS>;
+
+    fn mul(self, rhs: Scalar<S>) -> Self::Output {
+        &self * rhs
+    }
+}
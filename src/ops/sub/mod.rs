@@ -1,13 +1,13 @@
-use std::{array::from_fn, ops::Sub};
-use crate::matrix::{Matrix, MatrixRef};
+use std::{array::from_fn, ops::{Sub, SubAssign}};
+use crate::matrix::{Matrix, MatrixRef, TransposedRef};
 
-impl<'a, 'b, R, const ROWS: usize, const COLS: usize> Sub<MatrixRef<'b, R, ROWS, COLS>> for MatrixRef<'a, R, ROWS, COLS>
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<MatrixRef<'b, R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
 where
-    &'a R: Sub<&'b R, Output = R>
+    &'a R1: Sub<&'b R2, Output = R3>
 {
-    type Output = Matrix<R, ROWS, COLS>;
+    type Output = Matrix<R3, ROWS, COLS>;
 
-    fn sub(self, rhs: MatrixRef<'b, R, ROWS, COLS>) -> Self::Output {
+    fn sub(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
         let MatrixRef { vals: lhs } = self;
         let MatrixRef { vals: rhs } = rhs;
         let vals = from_fn(|i|
@@ -18,24 +18,170 @@ where
     }
 }
 
-impl<'a, 'b, R, const ROWS: usize, const COLS: usize> Sub<&'b Matrix<R, ROWS, COLS>> for &'a Matrix<R, ROWS, COLS>
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<&'b Matrix<R2, ROWS, COLS>> for &'a Matrix<R1, ROWS, COLS>
 where 
-    &'a R: Sub<&'b R, Output = R>
+    &'a R1: Sub<&'b R2, Output = R3>
 {
-    type Output = Matrix<R, ROWS, COLS>;
+    type Output = Matrix<R3, ROWS, COLS>;
 
-    fn sub(self, rhs: &'b Matrix<R, ROWS, COLS>) -> Self::Output {
+    fn sub(self, rhs: &'b Matrix<R2, ROWS, COLS>) -> Self::Output {
         self.as_ref() - rhs.as_ref()
     }
 }
 
-impl <R, const ROWS: usize, const COLS: usize> Sub for Matrix<R, ROWS, COLS>
+impl<R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<Matrix<R2, ROWS, COLS>> for Matrix<R1, ROWS, COLS>
 where
-    for<'a, 'b> &'a R: Sub<&'b R, Output = R>
+    for<'a, 'b> &'a R1: Sub<&'b R2, Output = R3>
 {
-    type Output = Self;
+    type Output = Matrix<R3, ROWS, COLS>;
 
-    fn sub(self, rhs: Self) -> Self::Output {
+    fn sub(self, rhs: Matrix<R2, ROWS, COLS>) -> Self::Output {
         &self - &rhs
     }
+}
+
+impl<'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<MatrixRef<'b, R2, ROWS, COLS>> for Matrix<R1, ROWS, COLS>
+where
+    for<'a> &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() - rhs
+    }
+}
+
+impl<'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<&'b Matrix<R2, ROWS, COLS>> for Matrix<R1, ROWS, COLS>
+where
+    for<'a> &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: &'b Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() - rhs.as_ref()
+    }
+}
+
+impl<'a, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<Matrix<R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
+where
+    for<'b> &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self - rhs.as_ref()
+    }
+}
+
+impl<'a, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<Matrix<R2, ROWS, COLS>> for &'a Matrix<R1, ROWS, COLS>
+where
+    for<'b> &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() - rhs.as_ref()
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<&'b Matrix<R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: &'b Matrix<R2, ROWS, COLS>) -> Self::Output {
+        self - rhs.as_ref()
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<MatrixRef<'b, R2, ROWS, COLS>> for &'a Matrix<R1, ROWS, COLS>
+where
+    &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        self.as_ref() - rhs
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<TransposedRef<'b, R2, ROWS, COLS>> for MatrixRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: TransposedRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        let vals = from_fn(|i|
+        from_fn(|j| &self.vals[i][j] - &rhs.vals[j][i]));
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<MatrixRef<'b, R2, ROWS, COLS>> for TransposedRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: MatrixRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        let vals = from_fn(|i|
+        from_fn(|j| &self.vals[j][i] - &rhs.vals[i][j]));
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<'a, 'b, R1, R2, R3, const ROWS: usize, const COLS: usize> Sub<TransposedRef<'b, R2, ROWS, COLS>> for TransposedRef<'a, R1, ROWS, COLS>
+where
+    &'a R1: Sub<&'b R2, Output = R3>
+{
+    type Output = Matrix<R3, ROWS, COLS>;
+
+    fn sub(self, rhs: TransposedRef<'b, R2, ROWS, COLS>) -> Self::Output {
+        let vals = from_fn(|i|
+        from_fn(|j| &self.vals[j][i] - &rhs.vals[j][i]));
+        Matrix {
+            vals
+        }
+    }
+}
+
+impl<'b, R, const ROWS: usize, const COLS: usize> SubAssign<MatrixRef<'b, R, ROWS, COLS>> for Matrix<R, ROWS, COLS>
+where
+    R: SubAssign<&'b R>
+{
+    fn sub_assign(&mut self, rhs: MatrixRef<'b, R, ROWS, COLS>) {
+        for (row, rhs_row) in self.vals.iter_mut().zip(rhs.vals) {
+            for (x, y) in row.iter_mut().zip(rhs_row) {
+                *x -= y;
+            }
+        }
+    }
+}
+
+impl<'b, R, const ROWS: usize, const COLS: usize> SubAssign<&'b Matrix<R, ROWS, COLS>> for Matrix<R, ROWS, COLS>
+where
+    R: SubAssign<&'b R>
+{
+    fn sub_assign(&mut self, rhs: &'b Matrix<R, ROWS, COLS>) {
+        *self -= rhs.as_ref();
+    }
+}
+
+impl<R, const ROWS: usize, const COLS: usize> SubAssign for Matrix<R, ROWS, COLS>
+where
+    R: SubAssign
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        for (row, rhs_row) in self.vals.iter_mut().zip(rhs.vals) {
+            for (x, y) in row.iter_mut().zip(rhs_row) {
+                *x -= y;
+            }
+        }
+    }
 }
\ No newline at end of file
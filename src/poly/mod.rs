@@ -0,0 +1,249 @@
+use std::{array::from_fn, ops::{Add, Mul, Neg, Sub}};
+use num_traits::{One, Zero};
+
+use crate::{algebra::{AdditiveMonoid, CommutativeRing, EuclideanDomain, Field, Ring, Semiring}, matrix::Matrix};
+
+// A univariate polynomial with coefficients in ascending order of degree and
+// no trailing zeros, so the zero polynomial has no coefficients at all. The
+// variable commutes with everything, which lets the coefficients themselves
+// be matrices.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Poly<R> {
+    coefficients: Vec<R>
+}
+
+impl<R: Zero> Poly<R> {
+    pub fn new(mut coefficients: Vec<R>) -> Self {
+        while coefficients.last().is_some_and(R::is_zero) {
+            coefficients.pop();
+        }
+        Self {
+            coefficients
+        }
+    }
+
+    pub fn constant(c: R) -> Self {
+        Self::new(vec![c])
+    }
+
+    // c x^degree
+    pub fn monomial(c: R, degree: usize) -> Self {
+        let mut coefficients: Vec<R> = (0..degree).map(|_| R::zero()).collect();
+        coefficients.push(c);
+        Self::new(coefficients)
+    }
+
+    pub fn x() -> Self
+    where
+        R: One
+    {
+        Self::monomial(R::one(), 1)
+    }
+
+    pub fn coefficients(&self) -> &[R] {
+        &self.coefficients
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    pub fn leading_coefficient(&self) -> Option<&R> {
+        self.coefficients.last()
+    }
+
+    // Horner's rule, with `x` multiplying from the right
+    pub fn evaluate(&self, x: &R) -> R
+    where
+        R: Semiring
+    {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(R::zero(), |acc, c| &(&acc * x) + c)
+    }
+}
+
+impl<R: Field> Poly<R> {
+    // Long division: (q, r) with self = q * divisor + r and deg r < deg divisor
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let Some(lead) = divisor.leading_coefficient() else {
+            panic!("polynomial division by zero");
+        };
+        let n = divisor.coefficients.len();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient: Vec<R> = (0..remainder.len().saturating_sub(n - 1)).map(|_| R::zero()).collect();
+        while remainder.len() >= n {
+            let shift = remainder.len() - n;
+            let q = &remainder[remainder.len() - 1] / lead;
+            for (r, d) in remainder[shift..].iter_mut().zip(&divisor.coefficients) {
+                *r = &*r - &(&q * d);
+            }
+            remainder.pop();
+            quotient[shift] = q;
+        }
+        (Self::new(quotient), Self::new(remainder))
+    }
+}
+
+impl<R: Semiring> Add<&Poly<R>> for &Poly<R> {
+    type Output = Poly<R>;
+
+    fn add(self, rhs: &Poly<R>) -> Poly<R> {
+        let zero = R::zero();
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Poly::new((0..len)
+            .map(|i| self.coefficients.get(i).unwrap_or(&zero) + rhs.coefficients.get(i).unwrap_or(&zero))
+            .collect())
+    }
+}
+
+impl<R: Ring> Sub<&Poly<R>> for &Poly<R> {
+    type Output = Poly<R>;
+
+    fn sub(self, rhs: &Poly<R>) -> Poly<R> {
+        let zero = R::zero();
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Poly::new((0..len)
+            .map(|i| self.coefficients.get(i).unwrap_or(&zero) - rhs.coefficients.get(i).unwrap_or(&zero))
+            .collect())
+    }
+}
+
+impl<R: Semiring> Mul<&Poly<R>> for &Poly<R> {
+    type Output = Poly<R>;
+
+    fn mul(self, rhs: &Poly<R>) -> Poly<R> {
+        let (a, b) = (&self.coefficients, &rhs.coefficients);
+        if a.is_empty() || b.is_empty() {
+            return Poly::zero();
+        }
+        Poly::new((0..a.len() + b.len() - 1)
+            .map(|k| (k.saturating_sub(b.len() - 1)..=k.min(a.len() - 1))
+                .map(|i| &a[i] * &b[k - i])
+                .fold(R::zero(), |x, y| &x + &y))
+            .collect())
+    }
+}
+
+impl<R: Ring> Neg for &Poly<R> {
+    type Output = Poly<R>;
+
+    fn neg(self) -> Poly<R> {
+        let zero = R::zero();
+        Poly::new(self.coefficients.iter().map(|c| &zero - c).collect())
+    }
+}
+
+impl<R: Semiring> Add for Poly<R> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Add::add(&self, &rhs)
+    }
+}
+
+impl<R: Ring> Sub for Poly<R> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Sub::sub(&self, &rhs)
+    }
+}
+
+impl<R: Semiring> Mul for Poly<R> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Mul::mul(&self, &rhs)
+    }
+}
+
+impl<R: Ring> Neg for Poly<R> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<R: Semiring> Zero for Poly<R> {
+    fn zero() -> Self {
+        Self {
+            coefficients: Vec::new()
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+}
+
+impl<R: Semiring> One for Poly<R> {
+    fn one() -> Self {
+        Self::constant(R::one())
+    }
+}
+
+impl<R: CommutativeRing> CommutativeRing for Poly<R> {}
+
+impl<R: Field> EuclideanDomain for Poly<R> {
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        Poly::div_rem(self, other)
+    }
+
+    // Makes the polynomial monic
+    fn normalizing_unit(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(lead) => Self::constant(&R::one() / lead),
+            None => Self::one()
+        }
+    }
+}
+
+impl<R: Ring, const DIM: usize> Matrix<R, DIM, DIM> {
+    // xI - A, whose determinant is the characteristic polynomial
+    pub fn characteristic_matrix(&self) -> Matrix<Poly<R>, DIM, DIM> {
+        let vals = from_fn(|i|
+            from_fn(|j| {
+                let c = &R::zero() - &self.vals[i][j];
+                match i == j {
+                    true => Poly::new(vec![c, R::one()]),
+                    false => Poly::constant(c)
+                }
+            })
+        );
+        Matrix {
+            vals
+        }
+    }
+}
+
+// A matrix of polynomials is a polynomial with matrix coefficients
+impl<R, const ROWS: usize, const COLS: usize> From<Matrix<Poly<R>, ROWS, COLS>> for Poly<Matrix<R, ROWS, COLS>>
+where
+    R: AdditiveMonoid + Clone
+{
+    fn from(m: Matrix<Poly<R>, ROWS, COLS>) -> Self {
+        let len = m.vals.iter().flatten().map(|p| p.coefficients.len()).max().unwrap_or(0);
+        Poly::new((0..len)
+            .map(|k| Matrix {
+                vals: from_fn(|i|
+                    from_fn(|j| m.vals[i][j].coefficients.get(k).cloned().unwrap_or_else(R::zero))
+                )
+            })
+            .collect())
+    }
+}
+
+impl<R: Zero + Clone, const ROWS: usize, const COLS: usize> From<Poly<Matrix<R, ROWS, COLS>>> for Matrix<Poly<R>, ROWS, COLS> {
+    fn from(p: Poly<Matrix<R, ROWS, COLS>>) -> Self {
+        let vals = from_fn(|i|
+            from_fn(|j| Poly::new(p.coefficients.iter().map(|c| c.vals[i][j].clone()).collect()))
+        );
+        Matrix {
+            vals
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::ops::{Add, Mul};
+use num_traits::{Bounded, CheckedAdd, One, Zero};
+
+// Tropical semiring: addition is min and multiplication is +, with
+// T::max_value() standing in for +infinity. Matrix powers over it give
+// shortest path lengths. Sums that overflow T saturate to +infinity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MinPlus<T>(pub T);
+
+// Addition is max and multiplication is +, with T::min_value() standing in
+// for -infinity. Matrix powers over it give longest path lengths. Sums that
+// overflow T fall back to -infinity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaxPlus<T>(pub T);
+
+// Addition is or and multiplication is and. Matrix powers over it give
+// reachability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Boolean(pub bool);
+
+impl<T> Add for MinPlus<T>
+where
+    T: PartialOrd
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match rhs.0 < self.0 {
+            true => rhs,
+            false => self
+        }
+    }
+}
+
+impl<T> Add<&MinPlus<T>> for &MinPlus<T>
+where
+    T: PartialOrd + Clone
+{
+    type Output = MinPlus<T>;
+
+    fn add(self, rhs: &MinPlus<T>) -> MinPlus<T> {
+        match rhs.0 < self.0 {
+            true => rhs.clone(),
+            false => self.clone()
+        }
+    }
+}
+
+impl<T> Mul for MinPlus<T>
+where
+    T: Bounded + PartialEq + CheckedAdd
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        match self.0 == T::max_value() || rhs.0 == T::max_value() {
+            true => Self(T::max_value()),
+            false => Self(self.0.checked_add(&rhs.0).unwrap_or_else(T::max_value))
+        }
+    }
+}
+
+impl<T> Mul<&MinPlus<T>> for &MinPlus<T>
+where
+    T: Bounded + PartialEq + CheckedAdd
+{
+    type Output = MinPlus<T>;
+
+    fn mul(self, rhs: &MinPlus<T>) -> MinPlus<T> {
+        match self.0 == T::max_value() || rhs.0 == T::max_value() {
+            true => MinPlus(T::max_value()),
+            false => MinPlus(self.0.checked_add(&rhs.0).unwrap_or_else(T::max_value))
+        }
+    }
+}
+
+impl<T> Zero for MinPlus<T>
+where
+    T: Bounded + PartialOrd
+{
+    fn zero() -> Self {
+        Self(T::max_value())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == T::max_value()
+    }
+}
+
+impl<T> One for MinPlus<T>
+where
+    T: Zero + Bounded + PartialEq + CheckedAdd
+{
+    fn one() -> Self {
+        Self(T::zero())
+    }
+}
+
+impl<T> Add for MaxPlus<T>
+where
+    T: PartialOrd
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        match rhs.0 > self.0 {
+            true => rhs,
+            false => self
+        }
+    }
+}
+
+impl<T> Add<&MaxPlus<T>> for &MaxPlus<T>
+where
+    T: PartialOrd + Clone
+{
+    type Output = MaxPlus<T>;
+
+    fn add(self, rhs: &MaxPlus<T>) -> MaxPlus<T> {
+        match rhs.0 > self.0 {
+            true => rhs.clone(),
+            false => self.clone()
+        }
+    }
+}
+
+impl<T> Mul for MaxPlus<T>
+where
+    T: Bounded + PartialEq + CheckedAdd
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        match self.0 == T::min_value() || rhs.0 == T::min_value() {
+            true => Self(T::min_value()),
+            false => Self(self.0.checked_add(&rhs.0).unwrap_or_else(T::min_value))
+        }
+    }
+}
+
+impl<T> Mul<&MaxPlus<T>> for &MaxPlus<T>
+where
+    T: Bounded + PartialEq + CheckedAdd
+{
+    type Output = MaxPlus<T>;
+
+    fn mul(self, rhs: &MaxPlus<T>) -> MaxPlus<T> {
+        match self.0 == T::min_value() || rhs.0 == T::min_value() {
+            true => MaxPlus(T::min_value()),
+            false => MaxPlus(self.0.checked_add(&rhs.0).unwrap_or_else(T::min_value))
+        }
+    }
+}
+
+impl<T> Zero for MaxPlus<T>
+where
+    T: Bounded + PartialOrd
+{
+    fn zero() -> Self {
+        Self(T::min_value())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == T::min_value()
+    }
+}
+
+impl<T> One for MaxPlus<T>
+where
+    T: Zero + Bounded + PartialEq + CheckedAdd
+{
+    fn one() -> Self {
+        Self(T::zero())
+    }
+}
+
+impl Add for Boolean {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 || rhs.0)
+    }
+}
+
+impl Add<&Boolean> for &Boolean {
+    type Output = Boolean;
+
+    fn add(self, rhs: &Boolean) -> Boolean {
+        Boolean(self.0 || rhs.0)
+    }
+}
+
+impl Mul for Boolean {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 && rhs.0)
+    }
+}
+
+impl Mul<&Boolean> for &Boolean {
+    type Output = Boolean;
+
+    fn mul(self, rhs: &Boolean) -> Boolean {
+        Boolean(self.0 && rhs.0)
+    }
+}
+
+impl Zero for Boolean {
+    fn zero() -> Self {
+        Self(false)
+    }
+
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for Boolean {
+    fn one() -> Self {
+        Self(true)
+    }
+}
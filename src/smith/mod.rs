@@ -0,0 +1,104 @@
+use crate::{algebra::{EuclideanDomain, Ring}, hermite::{identity, sub_multiple}, matrix::Matrix};
+
+// column target -= q * column source
+fn sub_multiple_column<R, const ROWS: usize, const COLS: usize>(m: &mut [[R; COLS]; ROWS], target: usize, source: usize, q: &R)
+where
+    R: Ring
+{
+    for row in m.iter_mut() {
+        row[target] = &row[target] - &(q * &row[source]);
+    }
+}
+
+fn swap_columns<R, const ROWS: usize, const COLS: usize>(m: &mut [[R; COLS]; ROWS], a: usize, b: usize) {
+    for row in m.iter_mut() {
+        row.swap(a, b);
+    }
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS>
+where
+    R: EuclideanDomain
+{
+    // Returns (U, D, V) with U and V unimodular, U A V = D diagonal, and each
+    // diagonal entry dividing the next
+    pub fn smith_normal_form(&self) -> (Matrix<R, ROWS, ROWS>, Self, Matrix<R, COLS, COLS>) {
+        let mut d = self.vals.clone();
+        let mut u = identity::<R, ROWS>();
+        let mut v = identity::<R, COLS>();
+
+        for t in 0..ROWS.min(COLS) {
+            // Move some nonzero entry of the remaining block to (t, t)
+            let Some((i, j)) = (t..ROWS)
+                .flat_map(|i| (t..COLS).map(move |j| (i, j)))
+                .find(|&(i, j)| !d[i][j].is_zero())
+            else {
+                break;
+            };
+            d.swap(t, i);
+            u.swap(t, i);
+            swap_columns(&mut d, t, j);
+            swap_columns(&mut v, t, j);
+
+            loop {
+                // Euclid's algorithm down column t, then along row t
+                // Only a nonzero remainder replaces the pivot, so it strictly
+                // shrinks and the two passes can't undo each other forever
+                for k in t + 1..ROWS {
+                    while !d[k][t].is_zero() {
+                        let (q, _) = d[k][t].div_rem(&d[t][t]);
+                        let (d_t, u_t) = (d[t].clone(), u[t].clone());
+                        sub_multiple(&mut d[k], &d_t, &q);
+                        sub_multiple(&mut u[k], &u_t, &q);
+                        if !d[k][t].is_zero() {
+                            d.swap(t, k);
+                            u.swap(t, k);
+                        }
+                    }
+                }
+                for k in t + 1..COLS {
+                    while !d[t][k].is_zero() {
+                        let (q, _) = d[t][k].div_rem(&d[t][t]);
+                        sub_multiple_column(&mut d, k, t, &q);
+                        sub_multiple_column(&mut v, k, t, &q);
+                        if !d[t][k].is_zero() {
+                            swap_columns(&mut d, t, k);
+                            swap_columns(&mut v, t, k);
+                        }
+                    }
+                }
+                if (t + 1..ROWS).any(|k| !d[k][t].is_zero()) {
+                    continue;
+                }
+
+                // The pivot must divide the rest of the block; otherwise fold
+                // an offending row into row t and reduce again
+                let offending = (t + 1..ROWS).find(|&i|
+                    (t + 1..COLS).any(|j| !d[i][j].div_rem(&d[t][t]).1.is_zero())
+                );
+                match offending {
+                    Some(i) => {
+                        let (d_i, u_i) = (d[i].clone(), u[i].clone());
+                        sub_multiple(&mut d[t], &d_i, &(&R::zero() - &R::one()));
+                        sub_multiple(&mut u[t], &u_i, &(&R::zero() - &R::one()));
+                    }
+                    None => break
+                }
+            }
+
+            let unit = d[t][t].normalizing_unit();
+            d[t].iter_mut().for_each(|x| *x = &unit * &*x);
+            u[t].iter_mut().for_each(|x| *x = &unit * &*x);
+        }
+        (Matrix { vals: u }, Matrix { vals: d }, Matrix { vals: v })
+    }
+
+    // The nonzero diagonal entries of the Smith normal form
+    pub fn invariant_factors(&self) -> Vec<R> {
+        let (_, d, _) = self.smith_normal_form();
+        (0..ROWS.min(COLS))
+            .map(|i| d.vals[i][i].clone())
+            .filter(|x| !x.is_zero())
+            .collect()
+    }
+}
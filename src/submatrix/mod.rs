@@ -0,0 +1,101 @@
+use std::{array::from_fn, ops::Index};
+
+use crate::{algebra::Ring, determinant::berkowitz, matrix::{Matrix, MatrixRef}};
+
+// A ROWS x COLS window into a larger matrix, borrowing one row slice per row
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubmatrixRef<'a, R, const ROWS: usize, const COLS: usize> {
+    pub(crate) rows: [&'a [R; COLS]; ROWS]
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> SubmatrixRef<'a, R, ROWS, COLS> {
+    pub fn get(&self, (row, col): (usize, usize)) -> Option<&'a R> {
+        self.rows.get(row)?.get(col)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<R, ROWS, COLS>
+    where
+        R: Clone
+    {
+        Matrix {
+            vals: self.rows.map(|row| row.clone())
+        }
+    }
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> Index<(usize, usize)> for SubmatrixRef<'a, R, ROWS, COLS> {
+    type Output = R;
+
+    fn index(&self, (row, col): (usize, usize)) -> &'a R {
+        &self.rows[row][col]
+    }
+}
+
+impl<'a, R, const ROWS: usize, const COLS: usize> MatrixRef<'a, R, ROWS, COLS> {
+    // The H x W window whose top left entry is at (ROW, COL)
+    pub fn submatrix_view<const ROW: usize, const COL: usize, const H: usize, const W: usize>(&self) -> SubmatrixRef<'a, R, H, W> {
+        const {
+            assert!(ROW + H <= ROWS, "submatrix rows out of range");
+            assert!(COL + W <= COLS, "submatrix columns out of range");
+        }
+        let vals = self.vals;
+        SubmatrixRef {
+            rows: from_fn(|i| vals[ROW + i][COL..COL + W].try_into().unwrap())
+        }
+    }
+
+    pub fn submatrix<const ROW: usize, const COL: usize, const H: usize, const W: usize>(&self) -> Matrix<R, H, W>
+    where
+        R: Clone
+    {
+        self.submatrix_view::<ROW, COL, H, W>().to_matrix()
+    }
+}
+
+impl<R, const ROWS: usize, const COLS: usize> Matrix<R, ROWS, COLS> {
+    pub fn submatrix_view<const ROW: usize, const COL: usize, const H: usize, const W: usize>(&self) -> SubmatrixRef<'_, R, H, W> {
+        self.as_ref().submatrix_view::<ROW, COL, H, W>()
+    }
+
+    pub fn submatrix<const ROW: usize, const COL: usize, const H: usize, const W: usize>(&self) -> Matrix<R, H, W>
+    where
+        R: Clone
+    {
+        self.as_ref().submatrix::<ROW, COL, H, W>()
+    }
+}
+
+impl<R, const DIM: usize> Matrix<R, DIM, DIM> {
+    // The matrix with row i and column j removed; SUB must be DIM - 1
+    pub fn minor<const SUB: usize>(&self, i: usize, j: usize) -> Matrix<R, SUB, SUB>
+    where
+        R: Clone
+    {
+        const {
+            assert!(SUB + 1 == DIM, "minor must have dimension DIM - 1");
+        }
+        assert!(i < DIM && j < DIM, "minor index out of range");
+        let vals = from_fn(|r|
+            from_fn(|c| self.vals[r + (r >= i) as usize][c + (c >= j) as usize].clone())
+        );
+        Matrix {
+            vals
+        }
+    }
+
+    // (-1)^(i + j) times the determinant of the (i, j) minor
+    pub fn cofactor(&self, i: usize, j: usize) -> R
+    where
+        R: Ring
+    {
+        assert!(i < DIM && j < DIM, "cofactor index out of range");
+        let vals = &self.vals;
+        let entry = |r: usize, c: usize| &vals[r + (r >= i) as usize][c + (c >= j) as usize];
+        let constant = berkowitz(DIM - 1, entry).pop().unwrap();
+        // det(M) = (-1)^n p(0) for the characteristic polynomial p of an n x n matrix M
+        match (DIM - 1 + i + j) % 2 {
+            0 => constant,
+            _ => &R::zero() - &constant
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::{fmt::Display, ops::{Add, Mul, Neg, Sub}};
+use num_traits::{One, Zero};
+
+use crate::algebra::CommutativeRing;
+
+// An integer modulo N, always stored in [0, N)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Zmod<const N: u64>(u64);
+
+// High 128 bits of the 256-bit product a * b
+fn mul_high(a: u128, b: u128) -> u128 {
+    const LOW: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & LOW);
+    let (b1, b0) = (b >> 64, b & LOW);
+    let (low, mid1, mid2) = (a0 * b0, a1 * b0, a0 * b1);
+    let carry = ((low >> 64) + (mid1 & LOW) + (mid2 & LOW)) >> 64;
+    a1 * b1 + (mid1 >> 64) + (mid2 >> 64) + carry
+}
+
+impl<const N: u64> Zmod<N> {
+    // floor((2^128 - 1) / N), so x / N is within 2 of mul_high(x, BARRETT)
+    const BARRETT: u128 = {
+        assert!(N > 0, "modulus must be positive");
+        u128::MAX / N as u128
+    };
+
+    // Barrett reduction of any x < 2^128
+    fn reduce(x: u128) -> Self {
+        let q = mul_high(x, Self::BARRETT);
+        let mut r = x - q * N as u128;
+        while r >= N as u128 {
+            r -= N as u128;
+        }
+        Self(r as u64)
+    }
+
+    pub fn new(value: u64) -> Self {
+        Self::reduce(value as u128)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(&self, mut exp: u64) -> Self {
+        let mut result = Self::one();
+        let mut base = *self;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    // The multiplicative inverse, which exists exactly when gcd(self, N) = 1
+    pub fn inverse(&self) -> Option<Self> {
+        // Extended Euclid, tracking only the coefficient of self
+        let (mut r0, mut r1) = (N as i128, self.0 as i128);
+        let (mut t0, mut t1) = (0i128, 1i128);
+        while r1 != 0 {
+            let q = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        match r0 {
+            1 => Some(Self(t0.rem_euclid(N as i128) as u64)),
+            _ => None
+        }
+    }
+}
+
+impl<const N: u64> From<u64> for Zmod<N> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: u64> From<i64> for Zmod<N> {
+    fn from(value: i64) -> Self {
+        let r = Self::new(value.unsigned_abs());
+        match value < 0 {
+            true => -r,
+            false => r
+        }
+    }
+}
+
+impl<const N: u64> Display for Zmod<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const N: u64> Add for Zmod<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        // Both are below N, so the sum is below 2N and fits in a u128
+        let sum = self.0 as u128 + rhs.0 as u128;
+        match sum >= N as u128 {
+            true => Self((sum - N as u128) as u64),
+            false => Self(sum as u64)
+        }
+    }
+}
+
+impl<const N: u64> Sub for Zmod<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        match self.0 >= rhs.0 {
+            true => Self(self.0 - rhs.0),
+            false => Self(N - (rhs.0 - self.0))
+        }
+    }
+}
+
+impl<const N: u64> Mul for Zmod<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::reduce(self.0 as u128 * rhs.0 as u128)
+    }
+}
+
+impl<const N: u64> Neg for Zmod<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(0) - self
+    }
+}
+
+impl<const N: u64> Add<&Zmod<N>> for &Zmod<N> {
+    type Output = Zmod<N>;
+
+    fn add(self, rhs: &Zmod<N>) -> Zmod<N> {
+        *self + *rhs
+    }
+}
+
+impl<const N: u64> Sub<&Zmod<N>> for &Zmod<N> {
+    type Output = Zmod<N>;
+
+    fn sub(self, rhs: &Zmod<N>) -> Zmod<N> {
+        *self - *rhs
+    }
+}
+
+impl<const N: u64> Mul<&Zmod<N>> for &Zmod<N> {
+    type Output = Zmod<N>;
+
+    fn mul(self, rhs: &Zmod<N>) -> Zmod<N> {
+        *self * *rhs
+    }
+}
+
+impl<const N: u64> Neg for &Zmod<N> {
+    type Output = Zmod<N>;
+
+    fn neg(self) -> Zmod<N> {
+        -*self
+    }
+}
+
+impl<const N: u64> Zero for Zmod<N> {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: u64> One for Zmod<N> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const N: u64> CommutativeRing for Zmod<N> {}
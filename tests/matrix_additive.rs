@@ -1,5 +1,31 @@
+#![allow(clippy::op_ref)]
+
+use std::ops::{Add, Sub};
+
 use matrix_rings::{Matrix, MatrixRef};
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Gaussian {
+    re: i64,
+    im: i64
+}
+
+impl Add<&i64> for &Gaussian {
+    type Output = Gaussian;
+
+    fn add(self, rhs: &i64) -> Gaussian {
+        Gaussian { re: self.re + rhs, im: self.im }
+    }
+}
+
+impl Sub<&i64> for &Gaussian {
+    type Output = Gaussian;
+
+    fn sub(self, rhs: &i64) -> Gaussian {
+        Gaussian { re: self.re - rhs, im: self.im }
+    }
+}
+
 #[test]
 fn test_matrix_addition_owned() {
     let vals1 = [[1, 2], [3, 4]];
@@ -161,4 +187,29 @@ fn test_matrix_subtraction_f64() {
             assert!((result[(i, j)] - expected[i][j]).abs() < f64::EPSILON);
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_matrix_addition_heterogeneous() {
+    let g = |re, im| Gaussian { re, im };
+    let gaussian = Matrix::new([[g(1, 1), g(2, -1)], [g(0, 3), g(-4, 0)]]);
+    let integer: Matrix<i64, 2, 2> = Matrix::new([[10, 20], [30, 40]]);
+    let expected = Matrix::new([[g(11, 1), g(22, -1)], [g(30, 3), g(36, 0)]]);
+
+    assert_eq!(gaussian.as_ref() + integer.as_ref(), expected);
+    assert_eq!(&gaussian + &integer, expected);
+    assert_eq!(gaussian + integer, expected);
+    assert_eq!(gaussian.as_ref() + integer.transposed_view(), Matrix::new([[g(11, 1), g(32, -1)], [g(20, 3), g(36, 0)]]));
+}
+
+#[test]
+fn test_matrix_subtraction_heterogeneous() {
+    let g = |re, im| Gaussian { re, im };
+    let gaussian = Matrix::new([[g(1, 1), g(2, -1)]]);
+    let integer: Matrix<i64, 1, 2> = Matrix::new([[1, 5]]);
+    let expected = Matrix::new([[g(0, 1), g(-3, -1)]]);
+
+    assert_eq!(gaussian.as_ref() - integer.as_ref(), expected);
+    assert_eq!(&gaussian - &integer, expected);
+    assert_eq!(gaussian - integer, expected);
+}
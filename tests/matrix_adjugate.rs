@@ -0,0 +1,82 @@
+use matrix_rings::{matrix, Matrix, Scalar};
+use num_traits::{One, Zero};
+
+fn inverse_mod(x: i64, n: i64) -> Option<i64> {
+    // Extended Euclid on (x mod n, n)
+    let (mut a, mut b) = (x.rem_euclid(n), n);
+    let (mut s, mut t) = (1, 0);
+    while b != 0 {
+        let q = a / b;
+        (a, b) = (b, a - q * b);
+        (s, t) = (t, s - q * t);
+    }
+    (a == 1).then(|| s.rem_euclid(n))
+}
+
+#[test]
+fn test_adjugate() {
+    let m = matrix![1, 2; 3, 4];
+    assert_eq!(m.adjugate(), matrix![4, -2; -3, 1]);
+
+    let m = matrix![
+        2, -1, 0;
+        1, 4, 2;
+        0, 1, -3
+    ];
+    let det = m.determinant();
+    assert_eq!(m * m.adjugate(), Scalar(det) * Matrix::one());
+    assert_eq!(m.adjugate() * m, Scalar(det) * Matrix::one());
+}
+
+#[test]
+fn test_adjugate_singular() {
+    let m = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9
+    ];
+    assert!((m * m.adjugate()).is_zero());
+}
+
+#[test]
+fn test_try_inverse_sl2z() {
+    let unit_inverse = |d: &i64| match d {
+        1 => Some(1),
+        -1 => Some(-1),
+        _ => None
+    };
+
+    let m = matrix![2i64, 3; 1, 2];
+    let inverse = m.try_inverse_with(unit_inverse).unwrap();
+    assert_eq!(inverse, matrix![2, -3; -1, 2]);
+    assert_eq!(m * inverse, Matrix::one());
+
+    let m = matrix![
+        1i64, 2, 0;
+        0, 1, 0;
+        3, 5, -1
+    ];
+    let inverse = m.try_inverse_with(unit_inverse).unwrap();
+    assert_eq!(m * inverse, Matrix::one());
+
+    // Determinant 2 is not a unit in Z
+    assert_eq!(matrix![2i64, 0; 0, 1].try_inverse_with(unit_inverse), None);
+}
+
+#[test]
+fn test_try_inverse_mod_n() {
+    // Hill cipher key over Z/26Z
+    let n = 26;
+    let key = matrix![3i64, 3; 2, 5];
+    let inverse = key.try_inverse_with(|d| inverse_mod(*d, n)).unwrap();
+    let product = key * inverse;
+    for i in 0..2 {
+        for j in 0..2 {
+            assert_eq!(product[(i, j)].rem_euclid(n), (i == j) as i64);
+        }
+    }
+
+    // Determinant 26 shares a factor with 26
+    let singular = matrix![2i64, 4; 1, 15];
+    assert_eq!(singular.try_inverse_with(|d| inverse_mod(*d, n)), None);
+}
@@ -0,0 +1,56 @@
+use matrix_rings::{matrix, CommutativeRing, EuclideanDomain, Field, Matrix, Ring, Semiring};
+
+// Downstream generic code only needs the short bounds
+fn sum_of_products<R: Semiring>(xs: &[R], ys: &[R]) -> R {
+    xs.iter().zip(ys).fold(R::zero(), |acc, (x, y)| &acc + &(x * y))
+}
+
+fn commutator<R: Ring>(a: &R, b: &R) -> R {
+    &(a * b) - &(b * a)
+}
+
+fn determinant_of<R: CommutativeRing, const DIM: usize>(m: &Matrix<R, DIM, DIM>) -> R {
+    m.determinant()
+}
+
+fn solve_2x2<R: Field>(a: &Matrix<R, 2, 2>, b: &Matrix<R, 2, 1>) -> Option<Matrix<R, 2, 1>> {
+    a.solve(b)
+}
+
+fn gcd_of_entries<R: EuclideanDomain, const ROWS: usize, const COLS: usize>(m: &Matrix<R, ROWS, COLS>) -> R {
+    m.invariant_factors().into_iter().next().unwrap_or_else(R::zero)
+}
+
+#[test]
+fn test_primitives() {
+    assert_eq!(sum_of_products(&[1, 2, 3], &[4, 5, 6]), 32);
+    assert_eq!(commutator(&3i64, &-7), 0);
+    assert_eq!(determinant_of(&matrix![1, 2; 3, 4]), -2);
+    assert_eq!(solve_2x2(&matrix![2.0, 0.0; 0.0, 4.0], &matrix![1.0; 1.0]), Some(matrix![0.5; 0.25]));
+    assert_eq!(gcd_of_entries(&matrix![4i32, 6; 10, 14]), 2);
+}
+
+#[test]
+fn test_square_matrices_form_a_ring() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 1, 0];
+    assert_eq!(commutator(&a, &b), matrix![-1, -3; 3, 1]);
+    assert_eq!(commutator(&a, &a), Matrix::new([[0; 2]; 2]));
+
+    let xs = [a, b];
+    assert_eq!(sum_of_products(&xs, &xs), a * a + b * b);
+}
+
+#[test]
+fn test_nested_matrices_form_a_ring() {
+    let a: Matrix<Matrix<i32, 2, 2>, 2, 2> = matrix![
+        matrix![1, 0; 0, 1], matrix![0, 1; 0, 0];
+        matrix![0, 0; 1, 0], matrix![2, 0; 0, 2]
+    ];
+    let b: Matrix<Matrix<i32, 2, 2>, 2, 2> = matrix![
+        matrix![0, 1; 1, 0], matrix![0, 0; 0, 0];
+        matrix![0, 0; 0, 0], matrix![1, 0; 0, 1]
+    ];
+    let flat = |m: Matrix<Matrix<i32, 2, 2>, 2, 2>| m.flatten::<4, 4>();
+    assert_eq!(flat(commutator(&a, &b)), commutator(&flat(a), &flat(b)));
+}
@@ -0,0 +1,111 @@
+use std::ops::{AddAssign, SubAssign};
+use matrix_rings::Matrix;
+use num_traits::{One, Zero};
+
+// Only supports the compound assignment operators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Counter(i64);
+
+impl AddAssign for Counter {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<'b> AddAssign<&'b Counter> for Counter {
+    fn add_assign(&mut self, rhs: &'b Counter) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Counter {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<'b> SubAssign<&'b Counter> for Counter {
+    fn sub_assign(&mut self, rhs: &'b Counter) {
+        self.0 -= rhs.0;
+    }
+}
+
+#[test]
+fn test_matrix_add_assign() {
+    let mut a = Matrix::new([[1, 2], [3, 4]]);
+    let b = Matrix::new([[5, 6], [7, 8]]);
+
+    a += b;
+    assert_eq!(a, Matrix::new([[6, 8], [10, 12]]));
+    a += &b;
+    assert_eq!(a, Matrix::new([[11, 14], [17, 20]]));
+    a += b.as_ref();
+    assert_eq!(a, Matrix::new([[16, 20], [24, 28]]));
+}
+
+#[test]
+fn test_matrix_sub_assign() {
+    let mut a = Matrix::new([[10, 10], [10, 10]]);
+    let b = Matrix::new([[1, 2], [3, 4]]);
+
+    a -= b;
+    assert_eq!(a, Matrix::new([[9, 8], [7, 6]]));
+    a -= &b;
+    assert_eq!(a, Matrix::new([[8, 6], [4, 2]]));
+    a -= b.as_ref();
+    assert_eq!(a, Matrix::new([[7, 4], [1, -2]]));
+}
+
+#[test]
+fn test_matrix_mul_assign() {
+    let mut a = Matrix::new([[1, 2], [3, 4]]);
+    let b = Matrix::new([[5, 6], [7, 8]]);
+    let expected = a * b;
+
+    a *= b;
+    assert_eq!(a, expected);
+
+    let mut c = Matrix::new([[1, 1], [0, 1]]);
+    c *= &c.clone();
+    assert_eq!(c, Matrix::new([[1, 2], [0, 1]]));
+    c *= Matrix::<i32, 2, 2>::one().as_ref();
+    assert_eq!(c, Matrix::new([[1, 2], [0, 1]]));
+}
+
+#[test]
+fn test_matrix_mul_assign_matches_pow() {
+    let a = Matrix::new([[1, -1, 0], [2, 0, 1], [0, 1, 1]]);
+    let mut power: Matrix<i32, 3, 3> = Matrix::one();
+    for n in 0..12 {
+        assert_eq!(power, a.pow(n));
+        power *= a;
+    }
+}
+
+#[test]
+fn test_matrix_assign_nested_accumulator() {
+    let block = Matrix::new([[1, 2], [3, 4]]);
+    let term: Matrix<Matrix<i32, 2, 2>, 2, 2> = Matrix::new([[block, block], [block, block]]);
+
+    let mut sum: Matrix<Matrix<i32, 2, 2>, 2, 2> = Matrix::zero();
+    for _ in 0..3 {
+        sum += &term;
+    }
+    assert_eq!(sum[(1, 0)], Matrix::new([[3, 6], [9, 12]]));
+
+    sum -= term;
+    assert_eq!(sum[(0, 1)], Matrix::new([[2, 4], [6, 8]]));
+}
+
+#[test]
+fn test_matrix_assign_only_compound_ops() {
+    let mut a = Matrix::new([[Counter(1), Counter(2)], [Counter(3), Counter(4)]]);
+    let b = Matrix::new([[Counter(10), Counter(20)], [Counter(30), Counter(40)]]);
+
+    a += b;
+    a += &b;
+    assert_eq!(a, Matrix::new([[Counter(21), Counter(42)], [Counter(63), Counter(84)]]));
+    a -= b.as_ref();
+    a -= b;
+    assert_eq!(a, Matrix::new([[Counter(1), Counter(2)], [Counter(3), Counter(4)]]));
+}
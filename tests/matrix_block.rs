@@ -0,0 +1,80 @@
+use matrix_rings::{direct_sum, hstack, matrix, vstack, Matrix};
+
+#[test]
+fn test_direct_sum() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6, 7];
+    let sum: Matrix<i32, 3, 5> = direct_sum(a, b);
+    assert_eq!(sum, matrix![
+        1, 2, 0, 0, 0;
+        3, 4, 0, 0, 0;
+        0, 0, 5, 6, 7
+    ]);
+}
+
+#[test]
+fn test_direct_sum_is_block_diagonal_ring_homomorphism() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1, 1; 2, 0, 1; 1, 1, 0];
+    let c = matrix![2, 0; 1, 1];
+    let d = matrix![1, 0, 2; 0, 1, 0; 3, 0, 1];
+
+    let left: Matrix<i32, 5, 5> = direct_sum(a, b);
+    let right: Matrix<i32, 5, 5> = direct_sum(c, d);
+    let expected: Matrix<i32, 5, 5> = direct_sum(a * c, b * d);
+    assert_eq!(left * right, expected);
+    assert_eq!(left.determinant(), a.determinant() * b.determinant());
+}
+
+#[test]
+fn test_hstack_and_vstack() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5; 6];
+    let c = matrix![7, 8, 9];
+
+    let wide: Matrix<i32, 2, 3> = hstack(a, b);
+    assert_eq!(wide, matrix![1, 2, 5; 3, 4, 6]);
+
+    let tall: Matrix<i32, 3, 3> = vstack(wide, c);
+    assert_eq!(tall, matrix![1, 2, 5; 3, 4, 6; 7, 8, 9]);
+}
+
+#[test]
+fn test_split_blocks() {
+    let m = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9
+    ];
+    let (a, b, c, d) = m.split_blocks::<1, 2, 2, 1>();
+    assert_eq!(a, matrix![1, 2]);
+    assert_eq!(b, matrix![3]);
+    assert_eq!(c, matrix![4, 5; 7, 8]);
+    assert_eq!(d, matrix![6; 9]);
+}
+
+#[test]
+fn test_split_blocks_inverts_stacking() {
+    let m = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12
+    ];
+    let (a, b, c, d): (Matrix<i32, 2, 1>, Matrix<i32, 2, 3>, Matrix<i32, 1, 1>, Matrix<i32, 1, 3>) = m.split_blocks();
+    let top: Matrix<i32, 2, 4> = hstack(a, b);
+    let bottom: Matrix<i32, 1, 4> = hstack(c, d);
+    let rebuilt: Matrix<i32, 3, 4> = vstack(top, bottom);
+    assert_eq!(rebuilt, m);
+}
+
+#[test]
+fn test_split_blocks_of_direct_sum() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6, 7];
+    let sum: Matrix<i32, 3, 5> = direct_sum(a, b);
+    let (top_left, top_right, bottom_left, bottom_right) = sum.split_blocks::<2, 2, 1, 3>();
+    assert_eq!(top_left, a);
+    assert_eq!(bottom_right, b);
+    assert_eq!(top_right, matrix![0, 0, 0; 0, 0, 0]);
+    assert_eq!(bottom_left, matrix![0, 0]);
+}
@@ -0,0 +1,69 @@
+use matrix_rings::Matrix;
+use num_traits::{One, Zero};
+
+#[test]
+fn test_characteristic_polynomial_2x2() {
+    // det(xI - A) = x^2 - 5x - 2
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    assert_eq!(a.characteristic_polynomial(), vec![-2, -5, 1]);
+}
+
+#[test]
+fn test_characteristic_polynomial_trace_and_determinant() {
+    let a = Matrix::new([
+        [2, -1, 0, 3],
+        [1, 4, 2, -2],
+        [0, 1, -3, 1],
+        [5, 0, 1, 1]
+    ]);
+    let coefficients = a.characteristic_polynomial();
+    assert_eq!(coefficients.len(), 5);
+    assert_eq!(coefficients[4], 1);
+    assert_eq!(coefficients[3], -a.trace());
+    assert_eq!(coefficients[0], a.determinant());
+}
+
+#[test]
+fn test_characteristic_polynomial_empty() {
+    let a: Matrix<i32, 0, 0> = Matrix::new([]);
+    assert_eq!(a.characteristic_polynomial(), vec![1]);
+}
+
+#[test]
+fn test_evaluate_polynomial() {
+    // A^2 + 2A + 3I
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    let result = a.evaluate_polynomial(&[3, 2, 1]);
+    assert_eq!(result, Matrix::new([[12, 14], [21, 33]]));
+
+    assert_eq!(a.evaluate_polynomial(&[]), Matrix::zero());
+    assert_eq!(a.evaluate_polynomial(&[1]), Matrix::one());
+}
+
+#[test]
+fn test_cayley_hamilton() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    assert!(a.evaluate_polynomial(&a.characteristic_polynomial()).is_zero());
+
+    let b = Matrix::new([
+        [2, -1, 0, 3, 1],
+        [1, 4, 2, -2, 0],
+        [0, 1, -3, 1, 2],
+        [5, 0, 1, 1, -1],
+        [1, 1, 1, 0, 2]
+    ]);
+    assert!(b.evaluate_polynomial(&b.characteristic_polynomial()).is_zero());
+}
+
+#[test]
+fn test_cayley_hamilton_nested() {
+    // Entries a*I + b*J with J = [[1, 1], [1, 0]] commute with each other
+    let entry = |a: i32, b: i32| Matrix::new([[a + b, b], [b, a]]);
+
+    let m: Matrix<Matrix<i32, 2, 2>, 3, 3> = Matrix::new([
+        [entry(1, 0), entry(2, 1), entry(-1, 1)],
+        [entry(0, 3), entry(1, 1), entry(2, 0)],
+        [entry(1, -2), entry(0, 1), entry(3, 1)]
+    ]);
+    assert!(m.evaluate_polynomial(&m.characteristic_polynomial()).is_zero());
+}
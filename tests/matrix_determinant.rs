@@ -0,0 +1,150 @@
+use std::ops::{Add, Mul, Sub};
+
+use matrix_rings::Matrix;
+use num_traits::{One, Zero};
+
+// Naive Leibniz formula, summing over all permutations
+fn leibniz<R, const DIM: usize>(m: &Matrix<R, DIM, DIM>) -> R
+where
+    R: Zero + One + Clone,
+    for<'a, 'b> &'a R: Add<&'b R, Output = R> + Sub<&'b R, Output = R> + Mul<&'b R, Output = R>
+{
+    fn permutations(n: usize) -> Vec<(Vec<usize>, bool)> {
+        if n == 0 {
+            return vec![(vec![], true)];
+        }
+        let mut result = Vec::new();
+        for (perm, even) in permutations(n - 1) {
+            for pos in 0..n {
+                let mut p = perm.clone();
+                p.insert(pos, n - 1);
+                // inserting at `pos` moves the new element past n - 1 - pos others
+                let swaps = n - 1 - pos;
+                result.push((p, even == swaps.is_multiple_of(2)));
+            }
+        }
+        result
+    }
+
+    permutations(DIM)
+        .into_iter()
+        .fold(R::zero(), |acc, (perm, even)| {
+            let term = (0..DIM).fold(R::one(), |x, i| &x * &m[(i, perm[i])]);
+            match even {
+                true => &acc + &term,
+                false => &acc - &term
+            }
+        })
+}
+
+#[test]
+fn test_determinant_small() {
+    let empty: Matrix<i32, 0, 0> = Matrix::new([]);
+    assert_eq!(empty.determinant(), 1);
+
+    let single = Matrix::new([[7]]);
+    assert_eq!(single.determinant(), 7);
+
+    let two = Matrix::new([[1, 2], [3, 4]]);
+    assert_eq!(two.determinant(), -2);
+
+    let three = Matrix::new([[2, 0, 1], [1, 3, 2], [1, 1, 2]]);
+    assert_eq!(three.determinant(), 6);
+}
+
+#[test]
+fn test_determinant_identity_and_singular() {
+    let identity: Matrix<i32, 5, 5> = Matrix::one();
+    assert_eq!(identity.determinant(), 1);
+
+    // Repeated row
+    let singular = Matrix::new([
+        [1, 2, 3, 4],
+        [5, 6, 7, 8],
+        [1, 2, 3, 4],
+        [9, 1, 2, 3]
+    ]);
+    assert_eq!(singular.determinant(), 0);
+}
+
+fn sample4() -> Matrix<i32, 4, 4> {
+    Matrix::new([
+        [4, 1, 5, -3],
+        [-3, -4, -2, 0],
+        [0, 4, 0, 3],
+        [-2, 2, -5, 0]
+    ])
+}
+
+#[test]
+fn test_determinant_matches_leibniz() {
+    let m2 = Matrix::new([[-5, 1], [-2, -5]]);
+    assert_eq!(m2.determinant(), 27);
+    assert_eq!(m2.determinant(), leibniz(&m2));
+
+    let m3 = Matrix::new([[4, 0, -4], [2, 5, -2], [-2, -3, 4]]);
+    assert_eq!(m3.determinant(), 40);
+    assert_eq!(m3.determinant(), leibniz(&m3));
+
+    let m4 = sample4();
+    assert_eq!(m4.determinant(), 87);
+    assert_eq!(m4.determinant(), leibniz(&m4));
+
+    let m5 = Matrix::new([
+        [3, 0, 4, 4, 3],
+        [5, -4, 5, 4, -5],
+        [-3, -2, -5, 2, -3],
+        [-4, 4, 4, -1, 0],
+        [-3, -1, -1, -2, -1]
+    ]);
+    assert_eq!(m5.determinant(), 4002);
+    assert_eq!(m5.determinant(), leibniz(&m5));
+
+    let m6 = Matrix::new([
+        [1, 1, 2, 0, -3, 3],
+        [-3, -4, -1, -2, 5, 4],
+        [3, 4, -1, 2, -1, -5],
+        [-3, -5, 5, 3, 1, -4],
+        [-5, -4, -1, -3, 2, -1],
+        [1, 3, 5, 4, -1, 4]
+    ]);
+    assert_eq!(m6.determinant(), 2267);
+    assert_eq!(m6.determinant(), leibniz(&m6));
+}
+
+#[test]
+fn test_determinant_matrix_ref() {
+    let m = sample4();
+    assert_eq!(m.as_ref().determinant(), m.determinant());
+}
+
+#[test]
+fn test_determinant_nested_matches_leibniz() {
+    // Entries a*I + b*J for a fixed J commute with each other, so the
+    // determinant over this subring of Matrix<i32, 2, 2> is well defined.
+    let j = Matrix::new([[0, 1], [2, 3]]);
+    let entry = |a: i32, b: i32| {
+        Matrix::new([[a, 0], [0, a]]) + Matrix::new([[b * j[(0, 0)], b * j[(0, 1)]], [b * j[(1, 0)], b * j[(1, 1)]]])
+    };
+
+    let m2: Matrix<Matrix<i32, 2, 2>, 2, 2> = Matrix::new([
+        [entry(1, 2), entry(0, 1)],
+        [entry(3, -1), entry(2, 2)]
+    ]);
+    assert_eq!(m2.determinant(), leibniz(&m2));
+
+    let m3: Matrix<Matrix<i32, 2, 2>, 3, 3> = Matrix::new([
+        [entry(1, 0), entry(2, 1), entry(-1, 1)],
+        [entry(0, 3), entry(1, 1), entry(2, 0)],
+        [entry(1, -2), entry(0, 1), entry(3, 1)]
+    ]);
+    assert_eq!(m3.determinant(), leibniz(&m3));
+
+    let m4: Matrix<Matrix<i32, 2, 2>, 4, 4> = Matrix::new([
+        [entry(1, 0), entry(2, 1), entry(-1, 1), entry(0, 2)],
+        [entry(0, 3), entry(1, 1), entry(2, 0), entry(1, -1)],
+        [entry(1, -2), entry(0, 1), entry(3, 1), entry(2, 2)],
+        [entry(2, 1), entry(-1, 0), entry(1, 1), entry(0, 1)]
+    ]);
+    assert_eq!(m4.determinant(), leibniz(&m4));
+}
@@ -0,0 +1,162 @@
+#![allow(clippy::op_ref)]
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use matrix_rings::{matrix, Matrix};
+use num_traits::{One, Zero};
+
+// Minimal exact rational type for field tests
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Q(i64, i64);
+
+fn q(n: i64) -> Q {
+    Q(n, 1)
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a.abs() } else { gcd(b, a % b) }
+}
+
+impl Q {
+    fn new(n: i64, d: i64) -> Q {
+        let g = gcd(n, d) * d.signum();
+        Q(n / g, d / g)
+    }
+}
+
+impl Add<&Q> for &Q {
+    type Output = Q;
+    fn add(self, rhs: &Q) -> Q { Q::new(self.0 * rhs.1 + rhs.0 * self.1, self.1 * rhs.1) }
+}
+
+impl Add for Q {
+    type Output = Q;
+    fn add(self, rhs: Q) -> Q { &self + &rhs }
+}
+
+impl Sub<&Q> for &Q {
+    type Output = Q;
+    fn sub(self, rhs: &Q) -> Q { Q::new(self.0 * rhs.1 - rhs.0 * self.1, self.1 * rhs.1) }
+}
+
+impl Mul<&Q> for &Q {
+    type Output = Q;
+    fn mul(self, rhs: &Q) -> Q { Q::new(self.0 * rhs.0, self.1 * rhs.1) }
+}
+
+impl Mul for Q {
+    type Output = Q;
+    fn mul(self, rhs: Q) -> Q { &self * &rhs }
+}
+
+impl Div<&Q> for &Q {
+    type Output = Q;
+    fn div(self, rhs: &Q) -> Q { Q::new(self.0 * rhs.1, self.1 * rhs.0) }
+}
+
+impl Zero for Q {
+    fn zero() -> Q { Q(0, 1) }
+    fn is_zero(&self) -> bool { self.0 == 0 }
+}
+
+impl One for Q {
+    fn one() -> Q { Q(1, 1) }
+}
+
+fn rational<const ROWS: usize, const COLS: usize>(m: Matrix<i64, ROWS, COLS>) -> Matrix<Q, ROWS, COLS> {
+    Matrix::new(std::array::from_fn(|i| std::array::from_fn(|j| q(m[(i, j)]))))
+}
+
+#[test]
+fn test_row_echelon() {
+    let m = rational(matrix![
+        0, 2, 4;
+        1, 1, 1;
+        2, 4, 6
+    ]);
+    let echelon = m.row_echelon();
+    for i in 0..3 {
+        for j in 0..i {
+            assert!(echelon[(i, j)].is_zero());
+        }
+    }
+    assert_eq!(echelon.rank(), m.rank());
+}
+
+#[test]
+fn test_reduced_row_echelon() {
+    let m = rational(matrix![
+        1, 2, 1, 4;
+        2, 4, 0, 2;
+        3, 6, 1, 6
+    ]);
+    assert_eq!(m.reduced_row_echelon(), rational(matrix![
+        1, 2, 0, 1;
+        0, 0, 1, 3;
+        0, 0, 0, 0
+    ]));
+}
+
+#[test]
+fn test_rank() {
+    assert_eq!(rational(matrix![1, 2; 2, 4]).rank(), 1);
+    assert_eq!(rational(matrix![1, 2; 3, 4]).rank(), 2);
+    assert_eq!(rational(matrix![0, 0, 0; 0, 0, 0]).rank(), 0);
+    assert_eq!(rational(matrix![1, 0, 2; 0, 1, 1; 1, 1, 3; 2, 0, 4]).rank(), 2);
+}
+
+#[test]
+fn test_try_inverse() {
+    let m = rational(matrix![
+        2, -1, 0;
+        -1, 2, -1;
+        0, -1, 2
+    ]);
+    let inverse = m.try_inverse().unwrap();
+    assert_eq!(inverse[(0, 0)], Q(3, 4));
+    assert_eq!(inverse[(1, 1)], Q(1, 1));
+    assert_eq!(m * inverse, Matrix::one());
+    assert_eq!(inverse * m, Matrix::one());
+
+    assert_eq!(rational(matrix![1, 2; 2, 4]).try_inverse(), None);
+}
+
+#[test]
+fn test_try_inverse_f64() {
+    let m: Matrix<f64, 2, 2> = matrix![4.0, 7.0; 2.0, 6.0];
+    let inverse = m.try_inverse().unwrap();
+    let expected = matrix![0.6, -0.7; -0.2, 0.4];
+    for i in 0..2 {
+        for j in 0..2 {
+            assert!((inverse[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn test_solve() {
+    let a = rational(matrix![
+        1, 1, 1;
+        0, 2, 5;
+        2, 5, -1
+    ]);
+    let b = rational(matrix![6; -4; 27]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(x, rational(matrix![5; 3; -2]));
+
+    // Several right-hand sides at once
+    let b = rational(matrix![6, 1; -4, 0; 27, 2]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(a * x, b);
+}
+
+#[test]
+fn test_solve_underdetermined_and_inconsistent() {
+    let a = rational(matrix![1, 2, 3; 2, 4, 6]);
+    let b = rational(matrix![6; 12]);
+    let x = a.solve(&b).unwrap();
+    assert_eq!(a * x, b);
+
+    let inconsistent = rational(matrix![6; 13]);
+    assert_eq!(a.solve(&inconsistent), None);
+}
@@ -0,0 +1,91 @@
+use matrix_rings::{matrix, Matrix};
+
+#[test]
+fn test_flatten() {
+    let a: Matrix<Matrix<i32, 2, 2>, 2, 2> = matrix![
+        matrix![1, 2; 5, 6], matrix![3, 4; 7, 8];
+        matrix![9, 10; 13, 14], matrix![11, 12; 15, 16]
+    ];
+    let flat: Matrix<i32, 4, 4> = a.flatten();
+    assert_eq!(flat, matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12;
+        13, 14, 15, 16
+    ]);
+}
+
+#[test]
+fn test_flatten_rectangular_blocks() {
+    // 2x1 outer matrix of 1x3 blocks
+    let a: Matrix<Matrix<i32, 1, 3>, 2, 1> = matrix![matrix![1, 2, 3]; matrix![4, 5, 6]];
+    let flat: Matrix<i32, 2, 3> = a.flatten();
+    assert_eq!(flat, matrix![1, 2, 3; 4, 5, 6]);
+}
+
+#[test]
+fn test_unflatten() {
+    let flat = matrix![
+        1, 2, 3, 4, 5, 6;
+        7, 8, 9, 10, 11, 12
+    ];
+    let blocks = flat.unflatten::<2, 2, 1, 3>();
+    assert_eq!(blocks[(0, 0)], matrix![1, 2; 7, 8]);
+    assert_eq!(blocks[(0, 2)], matrix![5, 6; 11, 12]);
+
+    let blocks: Matrix<Matrix<i32, 1, 3>, 2, 2> = flat.unflatten();
+    assert_eq!(blocks[(1, 1)], matrix![10, 11, 12]);
+}
+
+#[test]
+fn test_flatten_unflatten_round_trip() {
+    let flat = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12;
+        13, 14, 15, 16
+    ];
+    let blocks: Matrix<Matrix<i32, 2, 2>, 2, 2> = flat.unflatten();
+    assert_eq!(blocks.flatten::<4, 4>(), flat);
+
+    let strings = Matrix::new([[String::from("a"), String::from("b")], [String::from("c"), String::from("d")]]);
+    let blocks: Matrix<Matrix<String, 1, 2>, 2, 1> = strings.clone().unflatten();
+    assert_eq!(blocks.flatten::<2, 2>(), strings);
+}
+
+#[test]
+fn test_flatten_commutes_with_multiplication() {
+    let a: Matrix<Matrix<i32, 2, 3>, 2, 2> = matrix![
+        matrix![1, 2, 3; 4, 5, 6], matrix![2, 0, 1; 1, 3, 2];
+        matrix![1, 1, 1; 0, 1, 0], matrix![3, 2, 1; 1, 1, 1]
+    ];
+    let b: Matrix<Matrix<i32, 3, 2>, 2, 1> = matrix![
+        matrix![1, 0; 2, 1; 1, 2];
+        matrix![2, 3; 0, 1; 1, 0]
+    ];
+
+    let block_product: Matrix<i32, 4, 2> = (a * b).flatten();
+    let flat_a: Matrix<i32, 4, 6> = a.flatten();
+    let flat_b: Matrix<i32, 6, 2> = b.flatten();
+    assert_eq!(block_product, flat_a * flat_b);
+}
+
+#[test]
+fn test_unflatten_commutes_with_multiplication() {
+    let a = matrix![
+        1, 2, 0, -1;
+        3, 1, 2, 0;
+        0, 1, 1, 1;
+        2, 0, 1, 3
+    ];
+    let b = matrix![
+        2, 1, 0, 1;
+        0, 1, 3, 1;
+        1, 0, 1, 0;
+        1, 2, 0, 2
+    ];
+    let block_a: Matrix<Matrix<i32, 2, 2>, 2, 2> = a.unflatten();
+    let block_b: Matrix<Matrix<i32, 2, 2>, 2, 2> = b.unflatten();
+    let expected: Matrix<Matrix<i32, 2, 2>, 2, 2> = (a * b).unflatten();
+    assert_eq!(block_a * block_b, expected);
+}
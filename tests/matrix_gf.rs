@@ -0,0 +1,166 @@
+#![allow(clippy::op_ref)]
+
+use std::array::from_fn;
+
+use matrix_rings::{Matrix, GF};
+use num_traits::{One, Zero};
+
+type Gf256 = GF<2, 8>;
+
+#[test]
+fn test_gf_default_modulus() {
+    // The smallest irreducible octic over GF(2) is the AES polynomial
+    let a = Gf256::from_integer(0x57);
+    assert_eq!((a * Gf256::from_integer(0x83)).to_integer(), Some(0xC1));
+    assert_eq!(Gf256::from_integer(0x53).inverse(), Some(Gf256::from_integer(0xCA)));
+
+    // x^2 + 1 over GF(3), so x^2 = -1
+    let x = GF::<3, 2>::new([0, 1]);
+    assert_eq!(x * x, -GF::one());
+    assert_eq!(GF::<3, 2>::from_integer(7).coefficients(), [1, 2]);
+}
+
+#[test]
+fn test_gf_field_axioms() {
+    let elements: Vec<GF<3, 2>> = (0..9).map(GF::from_integer).collect();
+    for a in &elements {
+        assert_eq!(a + &-a, GF::zero());
+        match a.inverse() {
+            Some(b) => assert_eq!(a * &b, GF::one()),
+            None => assert!(a.is_zero())
+        }
+        for b in &elements {
+            assert_eq!(a * b, b * a);
+            if !b.is_zero() {
+                assert_eq!(&(a / b) * b, *a);
+            }
+            for c in &elements {
+                assert_eq!(a * &(b + c), &(a * b) + &(a * c));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_gf_custom_modulus() {
+    // x^8 + x^4 + x^3 + x^2 + 1 is primitive, so x has order 255
+    type Rs = GF<2, 8, 0x1D>;
+    let alpha = Rs::from_integer(2);
+    assert_eq!(alpha.pow(255), Rs::one());
+    assert!((1..255).all(|k| alpha.pow(k) != Rs::one()));
+    assert_eq!(alpha.pow(8).to_integer(), Some(0x1D));
+
+    // A large prime characteristic
+    type F = GF<1_000_000_007, 2>;
+    let a = F::new([3, 5]);
+    assert_eq!(a * a.inverse().unwrap(), F::one());
+}
+
+#[test]
+fn test_gf_default_modulus_large_fields() {
+    // x^16 + x^5 + x^3 + x + 1
+    type F16 = GF<2, 16>;
+    let mut top = [0; 16];
+    top[15] = 1;
+    assert_eq!((F16::new(top) * F16::from_integer(2)).to_integer(), Some(0x2B));
+    let a = F16::from_integer(0xBEEF);
+    assert_eq!(a * a.inverse().unwrap(), F16::one());
+    assert_eq!(a.pow(65535), F16::one());
+
+    // x^32 + x^7 + x^3 + x^2 + 1
+    type F32 = GF<2, 32>;
+    assert_eq!(F32::from_integer(2).pow(32).to_integer(), Some(0x8D));
+    let a = F32::from_integer(0xDEADBEEF);
+    assert_eq!(a.pow(u32::MAX as u64), F32::one());
+
+    // x^3 + 3x + 1, since x^3 + c has a root for every c when P = 2 mod 3
+    type F3 = GF<1_000_000_007, 3>;
+    let x = F3::new([0, 1, 0]);
+    assert_eq!(x.pow(3), F3::new([1_000_000_006, 1_000_000_004, 0]));
+    let a = F3::new([12, 34, 56]);
+    assert_eq!(a * a.inverse().unwrap(), F3::one());
+
+    // x^5 + 2x + 1
+    type F5 = GF<1_000_000_007, 5>;
+    let x = F5::new([0, 1, 0, 0, 0]);
+    assert_eq!(x.pow(5), F5::new([1_000_000_006, 1_000_000_005, 0, 0, 0]));
+    let a = F5::new([1, 2, 3, 4, 5]);
+    assert_eq!(a * a.inverse().unwrap(), F5::one());
+}
+
+#[test]
+fn test_gf_to_integer_overflow() {
+    // P^5 is above 2^128, so only elements of low enough degree fit
+    type F5 = GF<1_000_000_007, 5>;
+    assert_eq!(F5::new([7, 0, 0, 1, 0]).to_integer(), Some(1_000_000_007u128.pow(3) + 7));
+    assert_eq!(F5::new([0, 0, 0, 0, 1]).to_integer(), Some(1_000_000_007u128.pow(4)));
+    assert_eq!(F5::new([0, 0, 0, 0, 400]).to_integer(), None);
+    assert_eq!(F5::new([5, 4, 3, 2, 1]).to_integer().map(F5::from_integer), Some(F5::new([5, 4, 3, 2, 1])));
+}
+
+// Generalised Reed-Solomon code of length 8 and dimension 3 evaluated at the
+// points 1, ..., 8: G[i][j] = x_j^i, and its dual H[l][j] = v_j x_j^l with
+// v_j = 1 / prod_{i != j} (x_j - x_i)
+fn points() -> [Gf256; 8] {
+    from_fn(|j| Gf256::from_integer(j as u128 + 1))
+}
+
+fn generator() -> Matrix<Gf256, 3, 8> {
+    let x = points();
+    Matrix::new(from_fn(|i| from_fn(|j| x[j].pow(i as u64))))
+}
+
+fn parity_check() -> Matrix<Gf256, 5, 8> {
+    let x = points();
+    let v: [Gf256; 8] = from_fn(|j| (0..8)
+        .filter(|&i| i != j)
+        .fold(Gf256::one(), |acc, i| acc * (x[j] - x[i]))
+        .inverse()
+        .unwrap());
+    Matrix::new(from_fn(|l| from_fn(|j| v[j] * x[j].pow(l as u64))))
+}
+
+#[test]
+fn test_reed_solomon_matrices() {
+    let g = generator();
+    let h = parity_check();
+    assert!((g * h.transpose()).is_zero());
+    assert_eq!(g.rank(), 3);
+    assert_eq!(h.rank(), 5);
+
+    // Any three columns of a Vandermonde matrix are independent, so the
+    // systematic form starts with the identity
+    let systematic = g.reduced_row_echelon();
+    for i in 0..3 {
+        for j in 0..3 {
+            assert_eq!(systematic[(i, j)], if i == j { Gf256::one() } else { Gf256::zero() });
+        }
+    }
+    assert_eq!(systematic.rank(), 3);
+    assert!((systematic * h.transpose()).is_zero());
+
+    let vandermonde: Matrix<Gf256, 3, 3> = g.submatrix::<0, 0, 3, 3>();
+    let inverse = vandermonde.try_inverse().unwrap();
+    assert_eq!(vandermonde * inverse, Matrix::one());
+    assert_eq!(g.submatrix::<0, 5, 3, 3>().rank(), 3);
+}
+
+#[test]
+fn test_reed_solomon_decoding() {
+    let g = generator();
+    let h = parity_check();
+    let message: Matrix<Gf256, 1, 3> = Matrix::new([[0x12, 0xAB, 0x7F].map(Gf256::from_integer)]);
+    let codeword = message * g;
+    assert!((h * codeword.transpose()).is_zero());
+
+    // A single corrupted symbol gives a nonzero syndrome
+    let mut corrupted = codeword;
+    corrupted[(0, 5)] = corrupted[(0, 5)] + Gf256::one();
+    assert!(!(h * corrupted.transpose()).is_zero());
+
+    // Recover the message from any three surviving symbols
+    let kept = [1, 4, 6];
+    let g_kept: Matrix<Gf256, 3, 3> = Matrix::new(from_fn(|i| from_fn(|j| g[(i, kept[j])])));
+    let received: Matrix<Gf256, 3, 1> = Matrix::new(from_fn(|j| [codeword[(0, kept[j])]]));
+    assert_eq!(g_kept.transpose().solve(&received), Some(message.transpose()));
+}
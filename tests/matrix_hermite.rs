@@ -0,0 +1,98 @@
+use matrix_rings::{matrix, EuclideanDomain, Matrix};
+
+fn assert_hermite<const ROWS: usize, const COLS: usize>(a: Matrix<i64, ROWS, COLS>) {
+    let (h, u) = a.hermite_normal_form();
+    assert_eq!(u * a, h);
+
+    // Unimodular: det(U) = ±1
+    let det = u.determinant();
+    assert!(det == 1 || det == -1, "det(U) = {det}");
+
+    // Echelon form with positive pivots and reduced entries above them
+    let mut last_pivot = None;
+    for i in 0..ROWS {
+        match (0..COLS).find(|&j| h[(i, j)] != 0) {
+            Some(pivot) => {
+                assert!(last_pivot.is_none_or(|p| p < pivot));
+                assert!(h[(i, pivot)] > 0);
+                for above in 0..i {
+                    assert!(0 <= h[(above, pivot)] && h[(above, pivot)] < h[(i, pivot)]);
+                }
+                last_pivot = Some(pivot);
+            }
+            None => last_pivot = Some(COLS)
+        }
+    }
+}
+
+#[test]
+fn test_euclidean_domain_integers() {
+    assert_eq!(7i64.div_rem(&3), (2, 1));
+    assert_eq!((-7i64).div_rem(&3), (-3, 2));
+    assert_eq!(12i32.gcd(&18), 6);
+    assert_eq!((-5i64).normalizing_unit(), -1);
+    assert_eq!(5i64.normalizing_unit(), 1);
+}
+
+#[test]
+fn test_hermite_normal_form_known() {
+    let a = matrix![
+        2i64, 3, 6, 2;
+        5, 6, 1, 6;
+        8, 3, 1, 1
+    ];
+    let (h, _) = a.hermite_normal_form();
+    assert_eq!(h, matrix![
+        1, 0, 50, -11;
+        0, 3, 28, -2;
+        0, 0, 61, -13
+    ]);
+    assert_hermite(a);
+}
+
+#[test]
+fn test_hermite_normal_form_square() {
+    let a = matrix![
+        3i64, 1;
+        1, 1
+    ];
+    let (h, u) = a.hermite_normal_form();
+    assert_eq!(h, matrix![1, 1; 0, 2]);
+    assert_eq!(u * a, h);
+}
+
+#[test]
+fn test_hermite_normal_form_properties() {
+    assert_hermite(matrix![
+        4i64, -6, 2;
+        -2, 3, -1;
+        6, 0, 8;
+        1, 1, 1
+    ]);
+    assert_hermite(matrix![
+        0i64, 0, 5, 10;
+        0, 3, 7, -4
+    ]);
+    assert_hermite(matrix![
+        12i64, 18;
+        -8, 4;
+        30, 6
+    ]);
+    assert_hermite(matrix![0i64, 0; 0, 0]);
+}
+
+#[test]
+fn test_hermite_normal_form_is_canonical() {
+    // Row operations by a unimodular matrix do not change the HNF
+    let a = matrix![
+        2i64, 4, 4;
+        -6, 6, 12;
+        10, -4, -16
+    ];
+    let v = matrix![
+        1i64, 2, 0;
+        0, 1, 0;
+        3, 5, -1
+    ];
+    assert_eq!((v * a).hermite_normal_form().0, a.hermite_normal_form().0);
+}
@@ -0,0 +1,67 @@
+use matrix_rings::{kronecker, kronecker_flat, matrix, Matrix};
+
+#[test]
+fn test_kronecker_nested() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 5; 6, 7];
+    let ab = kronecker(&a, &b);
+
+    assert_eq!(ab[(0, 0)], matrix![0, 5; 6, 7]);
+    assert_eq!(ab[(0, 1)], matrix![0, 10; 12, 14]);
+    assert_eq!(ab[(1, 0)], matrix![0, 15; 18, 21]);
+    assert_eq!(ab[(1, 1)], matrix![0, 20; 24, 28]);
+}
+
+#[test]
+fn test_kronecker_flat() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 5; 6, 7];
+    let ab: Matrix<i32, 4, 4> = kronecker_flat(&a, &b);
+    assert_eq!(ab, matrix![
+        0, 5, 0, 10;
+        6, 7, 12, 14;
+        0, 15, 0, 20;
+        18, 21, 24, 28
+    ]);
+    assert_eq!(ab, kronecker(&a, &b).flatten::<4, 4>());
+}
+
+#[test]
+fn test_kronecker_rectangular() {
+    let a = matrix![1, -1, 2];
+    let b = matrix![1; 2];
+    let ab: Matrix<i32, 2, 3> = kronecker_flat(&a, &b);
+    assert_eq!(ab, matrix![1, -1, 2; 2, -2, 4]);
+}
+
+#[test]
+fn test_kronecker_mixed_product() {
+    // (A ⊗ B)(C ⊗ D) = AC ⊗ BD
+    let a = matrix![1, 2; 0, 1];
+    let b = matrix![2, 1, 0; 0, 1, 3];
+    let c = matrix![3, 1; 1, 0];
+    let d = matrix![1, 0; 2, 1; 0, 4];
+
+    let left: Matrix<i32, 4, 6> = kronecker_flat(&a, &b);
+    let right: Matrix<i32, 6, 4> = kronecker_flat(&c, &d);
+    let expected: Matrix<i32, 4, 4> = kronecker_flat(&(a * c), &(b * d));
+    assert_eq!(left * right, expected);
+
+    // The same identity holds for the nested form with block multiplication
+    assert_eq!(kronecker(&a, &b) * kronecker(&c, &d), kronecker(&(a * c), &(b * d)));
+}
+
+#[test]
+fn test_kronecker_heterogeneous() {
+    // Matrix<i32, 2, 2> entries times Matrix<i32, 2, 1> entries give Matrix<i32, 2, 1> entries
+    let rotate = matrix![0, -1; 1, 0];
+    let a: Matrix<Matrix<i32, 2, 2>, 1, 2> = matrix![rotate, rotate * rotate];
+    let b: Matrix<Matrix<i32, 2, 1>, 2, 1> = matrix![matrix![1; 0]; matrix![0; 1]];
+
+    let ab: Matrix<Matrix<Matrix<i32, 2, 1>, 2, 1>, 1, 2> = kronecker(&a, &b);
+    assert_eq!(ab[(0, 0)], matrix![matrix![0; 1]; matrix![-1; 0]]);
+    assert_eq!(ab[(0, 1)], matrix![matrix![-1; 0]; matrix![0; -1]]);
+
+    let flat: Matrix<Matrix<i32, 2, 1>, 2, 2> = kronecker_flat(&a, &b);
+    assert_eq!(flat[(1, 0)], matrix![-1; 0]);
+}
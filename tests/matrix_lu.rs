@@ -0,0 +1,125 @@
+use matrix_rings::{matrix, Matrix, Singular};
+use num_traits::One;
+
+fn assert_close<const ROWS: usize, const COLS: usize>(a: &Matrix<f64, ROWS, COLS>, b: &Matrix<f64, ROWS, COLS>) {
+    for i in 0..ROWS {
+        for j in 0..COLS {
+            assert!((a[(i, j)] - b[(i, j)]).abs() < 1e-10, "{a:?} != {b:?}");
+        }
+    }
+}
+
+#[test]
+fn test_lu_factors() {
+    let a: Matrix<f64, 3, 3> = matrix![
+        2.0, 1.0, 1.0;
+        4.0, -6.0, 0.0;
+        -2.0, 7.0, 2.0
+    ];
+    let lu = a.lu().unwrap();
+
+    // L is unit lower triangular and U is upper triangular
+    for i in 0..3 {
+        assert_eq!(lu.l()[(i, i)], 1.0);
+        for j in i + 1..3 {
+            assert_eq!(lu.l()[(i, j)], 0.0);
+            assert_eq!(lu.u()[(j, i)], 0.0);
+        }
+    }
+
+    // Partial pivoting picks the largest entry of the first column
+    assert_eq!(lu.permutation()[0], 1);
+
+    let permuted = Matrix::new(lu.permutation().map(|i| [a[(i, 0)], a[(i, 1)], a[(i, 2)]]));
+    assert_close(&(lu.l() * lu.u()), &permuted);
+}
+
+#[test]
+fn test_lu_determinant() {
+    let a: Matrix<f64, 3, 3> = matrix![
+        2.0, 1.0, 1.0;
+        4.0, -6.0, 0.0;
+        -2.0, 7.0, 2.0
+    ];
+    assert!((a.lu().unwrap().determinant() - a.determinant()).abs() < 1e-10);
+
+    let swap: Matrix<f64, 2, 2> = matrix![0.0, 1.0; 1.0, 0.0];
+    assert_eq!(swap.lu().unwrap().determinant(), -1.0);
+}
+
+#[test]
+fn test_lu_solve_and_inverse() {
+    let a: Matrix<f64, 3, 3> = matrix![
+        1.0, 2.0, 3.0;
+        0.0, 1.0, 4.0;
+        5.0, 6.0, 0.0
+    ];
+    let lu = a.lu().unwrap();
+
+    let b = matrix![1.0; 2.0; 3.0];
+    let x = lu.solve(&b);
+    assert_close(&(a * x), &b);
+
+    let inverse = lu.inverse();
+    assert_close(&inverse, &matrix![
+        -24.0, 18.0, 5.0;
+        20.0, -15.0, -4.0;
+        -5.0, 4.0, 1.0
+    ]);
+    assert_close(&(a * inverse), &Matrix::one());
+}
+
+#[test]
+fn test_lu_f32() {
+    let a: Matrix<f32, 2, 2> = matrix![4.0, 3.0; 6.0, 3.0];
+    let lu = a.lu().unwrap();
+    assert!((lu.determinant() + 6.0).abs() < 1e-5);
+    let x = lu.solve(&matrix![10.0; 12.0]);
+    assert!((x[(0, 0)] - 1.0).abs() < 1e-5);
+    assert!((x[(1, 0)] - 2.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_lu_singular() {
+    let a: Matrix<f64, 3, 3> = matrix![
+        1.0, 2.0, 3.0;
+        4.0, 5.0, 6.0;
+        7.0, 8.0, 9.0
+    ];
+    assert_eq!(a.lu(), Err(Singular));
+
+    let zero: Matrix<f64, 2, 2> = matrix![0.0, 0.0; 0.0, 0.0];
+    assert_eq!(zero.lu(), Err(Singular));
+
+    let nan: Matrix<f64, 2, 2> = matrix![f64::NAN, 1.0; 1.0, 1.0];
+    assert_eq!(nan.lu(), Err(Singular));
+}
+
+#[test]
+fn test_lu_badly_scaled() {
+    // Nonsingular, however small one row is next to the other
+    let a: Matrix<f64, 2, 2> = matrix![1e20, 0.0; 0.0, 1.0];
+    let lu = a.lu().unwrap();
+    assert_eq!(lu.determinant(), 1e20);
+    assert_eq!(lu.solve(&matrix![1e20; 2.0]), matrix![1.0; 2.0]);
+
+    let b: Matrix<f64, 3, 3> = matrix![
+        1e-30, 2e-30, 0.0;
+        3.0, 1.0, 0.0;
+        0.0, 0.0, 1e30
+    ];
+    let lu = b.lu().unwrap();
+    assert!((lu.determinant() + 5.0).abs() < 1e-12);
+    assert_close(&lu.solve(&matrix![3e-30; 4.0; 1e30]), &matrix![1.0; 1.0; 1.0]);
+
+    // Nonsingular, however large one column is next to the other
+    let c: Matrix<f32, 2, 2> = Matrix::new([[1.0, 1e7], [1.0, -1e7]]);
+    let lu = c.lu().unwrap();
+    assert_eq!(lu.determinant(), -2e7);
+    assert_eq!(lu.inverse(), matrix![0.5, 0.5; 5e-8, -5e-8]);
+
+    let d: Matrix<f64, 2, 2> = Matrix::new([[1.0, 1e16], [1.0, -1e16]]);
+    let lu = d.lu().unwrap();
+    assert_eq!(lu.determinant(), -2e16);
+    assert_eq!(lu.inverse(), matrix![0.5, 0.5; 5e-17, -5e-17]);
+}
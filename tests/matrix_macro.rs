@@ -0,0 +1,91 @@
+use matrix_rings::{matrix, matrix_ref, Matrix, MatrixRef};
+
+#[test]
+fn test_matrix_macro() {
+    let a = matrix![1, 2; 3, 4];
+    assert_eq!(a, Matrix::new([[1, 2], [3, 4]]));
+
+    let b: Matrix<i32, 2, 3> = matrix![
+        1, 2, 3;
+        4, 5, 6;
+    ];
+    assert_eq!(b, Matrix::new([[1, 2, 3], [4, 5, 6]]));
+}
+
+#[test]
+fn test_matrix_macro_shapes() {
+    let row: Matrix<i32, 1, 3> = matrix![1, 2, 3];
+    assert_eq!(row, Matrix::new([[1, 2, 3]]));
+
+    let column: Matrix<i32, 3, 1> = matrix![1; 2; 3];
+    assert_eq!(column, Matrix::new([[1], [2], [3]]));
+
+    let single = matrix![7];
+    assert_eq!(single, Matrix::new([[7]]));
+}
+
+#[test]
+fn test_matrix_macro_expressions() {
+    let x = 5;
+    let a = matrix![x + 1, x * 2; -x, x - 5];
+    assert_eq!(a, Matrix::new([[6, 10], [-5, 0]]));
+}
+
+#[test]
+fn test_matrix_macro_nested_blocks() {
+    let a11 = matrix![1, 0; 0, 1];
+    let a12 = matrix![2, 0; 0, 2];
+    let a21 = matrix![0, 0; 0, 0];
+    let a22 = matrix![1, 1; 1, 1];
+
+    let block: Matrix<Matrix<i32, 2, 2>, 2, 2> = matrix![a11, a12; a21, a22];
+    assert_eq!(block, Matrix::new([[a11, a12], [a21, a22]]));
+
+    let literal = matrix![
+        matrix![1, 0; 0, 1], matrix![2, 0; 0, 2];
+        matrix![0, 0; 0, 0], matrix![1, 1; 1, 1]
+    ];
+    assert_eq!(literal, block);
+}
+
+#[test]
+fn test_matrix_ref_macro() {
+    let a: MatrixRef<i32, 2, 2> = matrix_ref![1, 2; 3, 4];
+    assert_eq!(a[(0, 1)], 2);
+    assert_eq!(a.to_matrix(), matrix![1, 2; 3, 4]);
+    assert_eq!(a * matrix_ref![1, 0; 0, 1], matrix![1, 2; 3, 4]);
+}
+
+#[test]
+fn test_matrix_ref_macro_non_literal_entries() {
+    let (x, y) = (2, 3);
+    let a = matrix![1, 2; 3, 4];
+    assert_eq!(a.as_ref() * matrix_ref![x, 0; 0, x], matrix![2, 4; 6, 8]);
+    assert_eq!(matrix_ref![x, y; y, x] + a.as_ref(), matrix![3, 5; 6, 6]);
+    assert_eq!(matrix_ref![x, y; y, x].to_matrix(), matrix![2, 3; 3, 2]);
+}
+
+#[test]
+fn test_matrix_macro_large_literals() {
+    let wide: Matrix<i32, 1, 140> = matrix![
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+        20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39,
+        40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59,
+        60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79,
+        80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99,
+        100, 101, 102, 103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 113, 114, 115, 116, 117, 118, 119,
+        120, 121, 122, 123, 124, 125, 126, 127, 128, 129, 130, 131, 132, 133, 134, 135, 136, 137, 138, 139
+    ];
+    assert_eq!(wide, Matrix::new([std::array::from_fn(|j| j as i32)]));
+
+    let tall: Matrix<i32, 140, 1> = matrix![
+        0; 1; 2; 3; 4; 5; 6; 7; 8; 9; 10; 11; 12; 13; 14; 15; 16; 17; 18; 19;
+        20; 21; 22; 23; 24; 25; 26; 27; 28; 29; 30; 31; 32; 33; 34; 35; 36; 37; 38; 39;
+        40; 41; 42; 43; 44; 45; 46; 47; 48; 49; 50; 51; 52; 53; 54; 55; 56; 57; 58; 59;
+        60; 61; 62; 63; 64; 65; 66; 67; 68; 69; 70; 71; 72; 73; 74; 75; 76; 77; 78; 79;
+        80; 81; 82; 83; 84; 85; 86; 87; 88; 89; 90; 91; 92; 93; 94; 95; 96; 97; 98; 99;
+        100; 101; 102; 103; 104; 105; 106; 107; 108; 109; 110; 111; 112; 113; 114; 115; 116; 117; 118; 119;
+        120; 121; 122; 123; 124; 125; 126; 127; 128; 129; 130; 131; 132; 133; 134; 135; 136; 137; 138; 139
+    ];
+    assert_eq!(tall, Matrix::new(std::array::from_fn(|i| [i as i32])));
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::op_ref)]
+
+use std::ops::Add;
+
+use matrix_rings::Matrix;
+
+// A non-Copy element that only implements borrowed addition
+#[derive(Clone, Debug, PartialEq)]
+struct Word(String);
+
+impl From<&str> for Word {
+    fn from(s: &str) -> Self {
+        Word(s.to_string())
+    }
+}
+
+impl Add<&Word> for &Word {
+    type Output = Word;
+
+    fn add(self, rhs: &Word) -> Word {
+        Word(format!("{}{}", self.0, rhs.0))
+    }
+}
+
+#[test]
+fn test_mixed_addition() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    let b = Matrix::new([[5, 6], [7, 8]]);
+    let expected = Matrix::new([[6, 8], [10, 12]]);
+
+    assert_eq!(a + &b, expected);
+    assert_eq!(a + b.as_ref(), expected);
+    assert_eq!(&a + b, expected);
+    assert_eq!(a.as_ref() + b, expected);
+    assert_eq!(&a + b.as_ref(), expected);
+    assert_eq!(a.as_ref() + &b, expected);
+}
+
+#[test]
+fn test_mixed_subtraction() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    let b = Matrix::new([[5, 6], [7, 8]]);
+    let expected = Matrix::new([[-4, -4], [-4, -4]]);
+
+    assert_eq!(a - &b, expected);
+    assert_eq!(a - b.as_ref(), expected);
+    assert_eq!(&a - b, expected);
+    assert_eq!(a.as_ref() - b, expected);
+    assert_eq!(&a - b.as_ref(), expected);
+    assert_eq!(a.as_ref() - &b, expected);
+}
+
+#[test]
+fn test_mixed_multiplication() {
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let b = Matrix::new([[7, 8], [9, 10], [11, 12]]);
+    let expected = Matrix::new([[58, 64], [139, 154]]);
+
+    assert_eq!(a * &b, expected);
+    assert_eq!(a * b.as_ref(), expected);
+    assert_eq!(&a * b, expected);
+    assert_eq!(a.as_ref() * b, expected);
+    assert_eq!(&a * b.as_ref(), expected);
+    assert_eq!(a.as_ref() * &b, expected);
+}
+
+#[test]
+fn test_mixed_negation() {
+    let a = Matrix::new([[1, -2], [3, 0]]);
+    let expected = Matrix::new([[-1, 2], [-3, 0]]);
+
+    assert_eq!(-a, expected);
+    assert_eq!(-&a, expected);
+    assert_eq!(-a.as_ref(), expected);
+}
+
+#[test]
+fn test_mixed_operands_non_copy() {
+    let a = Matrix::new([[Word::from("a"), Word::from("b")]]);
+    let b = Matrix::new([[Word::from("c"), Word::from("d")]]);
+    let expected = Matrix::new([[Word::from("ac"), Word::from("bd")]]);
+
+    assert_eq!(a.clone() + &b, expected);
+    assert_eq!(&a + b.clone(), expected);
+    assert_eq!(a.as_ref() + b.clone(), expected);
+    assert_eq!(a + b, expected);
+}
+
+#[test]
+fn test_mixed_operands_nested() {
+    let block = Matrix::new([[1, 2], [3, 4]]);
+    let a: Matrix<Matrix<i32, 2, 2>, 2, 2> = Matrix::new([[block, block], [block, block]]);
+    let b = a;
+
+    let sum = a + &b;
+    assert_eq!(sum[(0, 1)], Matrix::new([[2, 4], [6, 8]]));
+
+    let product = &a * b.as_ref();
+    assert_eq!(product[(1, 1)], block * block + block * block);
+
+    let difference = a.as_ref() - b;
+    assert_eq!(difference[(1, 0)], Matrix::new([[0, 0], [0, 0]]));
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::op_ref)]
+
 use matrix_rings::Matrix;
 
 #[test]
@@ -0,0 +1,66 @@
+use matrix_rings::{Matrix, MatrixMut, MatrixRef};
+
+#[test]
+fn test_matrix_index_mut() {
+    let mut matrix = Matrix::new([[1, 2], [3, 4]]);
+    matrix[(0, 1)] = 7;
+    matrix[(1, 0)] += 10;
+    assert_eq!(matrix, Matrix::new([[1, 7], [13, 4]]));
+}
+
+#[test]
+fn test_matrix_index_mut_nested() {
+    let mut matrix: Matrix<Matrix<i32, 2, 2>, 2, 2> = Matrix::new([[Matrix::new([[0, 0], [0, 0]]); 2]; 2]);
+    matrix[(1, 1)][(0, 1)] = 5;
+    assert_eq!(matrix[(1, 1)], Matrix::new([[0, 5], [0, 0]]));
+    assert_eq!(matrix[(0, 0)], Matrix::new([[0, 0], [0, 0]]));
+}
+
+#[test]
+fn test_matrix_mut() {
+    let mut vals = [[1, 2, 3], [4, 5, 6]];
+    let mut view = MatrixMut::new(&mut vals);
+    view[(0, 0)] = 10;
+    view[(1, 2)] *= 2;
+    assert_eq!(view[(0, 0)], 10);
+    assert_eq!(view.as_ref()[(1, 2)], 12);
+    assert_eq!(view.to_matrix(), Matrix::new([[10, 2, 3], [4, 5, 12]]));
+    assert_eq!(vals, [[10, 2, 3], [4, 5, 12]]);
+}
+
+#[test]
+fn test_matrix_as_mut() {
+    let mut matrix = Matrix::new([[1, 2], [3, 4]]);
+    {
+        let mut view = matrix.as_mut();
+        if let Some(x) = view.get_mut((1, 1)) {
+            *x = 0;
+        }
+        assert!(view.get_mut((2, 0)).is_none());
+    }
+    assert_eq!(matrix, Matrix::new([[1, 2], [3, 0]]));
+}
+
+#[test]
+fn test_matrix_get() {
+    let mut matrix = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(matrix.get((1, 2)), Some(&6));
+    assert_eq!(matrix.get((2, 0)), None);
+    assert_eq!(matrix.get((0, 3)), None);
+
+    *matrix.get_mut((0, 0)).unwrap() = 9;
+    assert_eq!(matrix[(0, 0)], 9);
+    assert!(matrix.get_mut((5, 5)).is_none());
+}
+
+#[test]
+fn test_matrix_ref_and_transposed_get() {
+    let vals = [[1, 2, 3], [4, 5, 6]];
+    let matrix_ref = MatrixRef::new(&vals);
+    assert_eq!(matrix_ref.get((0, 2)), Some(&3));
+    assert_eq!(matrix_ref.get((2, 0)), None);
+
+    let transposed = matrix_ref.transposed_view();
+    assert_eq!(transposed.get((2, 0)), Some(&3));
+    assert_eq!(transposed.get((0, 2)), None);
+}
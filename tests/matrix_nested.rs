@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use matrix_rings::Matrix;
 use num_traits::{Zero, One};
 
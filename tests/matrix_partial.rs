@@ -0,0 +1,53 @@
+use std::array::from_fn;
+
+use matrix_rings::{matrix, Matrix, Scalar};
+
+// Nested form of the tensor product A ⊗ B, with A as the outer factor
+fn tensor<const N: usize, const P: usize>(a: &Matrix<i32, N, N>, b: &Matrix<i32, P, P>) -> Matrix<Matrix<i32, P, P>, N, N> {
+    Matrix::new(from_fn(|i| from_fn(|j| Scalar(a[(i, j)]) * b)))
+}
+
+#[test]
+fn test_partial_trace_of_tensor_product() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![2, 0, 1; 1, 3, 0; 0, 1, 5];
+    let ab = tensor(&a, &b);
+
+    assert_eq!(ab.partial_trace_outer(), Scalar(a.trace()) * b);
+    assert_eq!(ab.partial_trace_inner(), Scalar(b.trace()) * a);
+}
+
+#[test]
+fn test_partial_trace_general() {
+    let m: Matrix<Matrix<i32, 2, 2>, 2, 2> = matrix![
+        matrix![1, 2; 3, 4], matrix![5, 6; 7, 8];
+        matrix![9, 10; 11, 12], matrix![13, 14; 15, 16]
+    ];
+    assert_eq!(m.partial_trace_outer(), matrix![14, 16; 18, 20]);
+    assert_eq!(m.partial_trace_inner(), matrix![5, 13; 21, 29]);
+
+    // Both partial traces give back the full trace
+    let flat: Matrix<i32, 4, 4> = m.flatten();
+    assert_eq!(m.partial_trace_outer().trace(), flat.trace());
+    assert_eq!(m.partial_trace_inner().trace(), flat.trace());
+}
+
+#[test]
+fn test_partial_transpose_of_tensor_product() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![0, 1; 5, 7];
+    let ab = tensor(&a, &b);
+
+    assert_eq!(ab.partial_transpose_outer(), tensor(&a.transpose(), &b));
+    assert_eq!(ab.partial_transpose_inner(), tensor(&a, &b.transpose()));
+}
+
+#[test]
+fn test_partial_transposes_compose_to_full_transpose() {
+    let m: Matrix<Matrix<i32, 2, 3>, 1, 2> = matrix![
+        matrix![1, 2, 3; 4, 5, 6], matrix![7, 8, 9; 10, 11, 12]
+    ];
+    let flat: Matrix<i32, 2, 6> = m.flatten();
+    let both: Matrix<i32, 6, 2> = m.partial_transpose_outer().partial_transpose_inner().flatten();
+    assert_eq!(both, flat.transpose());
+}
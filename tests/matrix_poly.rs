@@ -0,0 +1,117 @@
+#![allow(clippy::op_ref)]
+
+use matrix_rings::{matrix, EuclideanDomain, Matrix, Poly, GF};
+use num_traits::{One, Zero};
+
+type F7 = GF<7, 1>;
+
+fn f7(coefficients: &[u64]) -> Poly<F7> {
+    Poly::new(coefficients.iter().map(|&c| F7::from_integer(c as u128)).collect())
+}
+
+#[test]
+fn test_poly_arithmetic() {
+    let x: Poly<i64> = Poly::x();
+    let p = &x + &Poly::one();
+    assert_eq!(&p * &p, Poly::new(vec![1, 2, 1]));
+    assert_eq!(&(&p * &p) - &(&x * &x), Poly::new(vec![1, 2]));
+    assert_eq!(-&p, Poly::new(vec![-1, -1]));
+    assert_eq!(&p - &p, Poly::zero());
+    assert_eq!(Poly::new(vec![3, 0, 0]), Poly::constant(3));
+
+    assert_eq!(Poly::<i64>::zero().degree(), None);
+    assert_eq!(Poly::monomial(5i64, 4).degree(), Some(4));
+    assert_eq!(Poly::new(vec![1, -3, 2]).leading_coefficient(), Some(&2));
+    assert_eq!(Poly::new(vec![1, -3, 2]).evaluate(&4), 21);
+}
+
+#[test]
+fn test_poly_division() {
+    // x^3 - 2x^2 - 4 = (x - 3)(x^2 + x + 3) + 5
+    let a = Poly::new(vec![-4.0, 0.0, -2.0, 1.0]);
+    let b = Poly::new(vec![-3.0, 1.0]);
+    assert_eq!(a.div_rem(&b), (Poly::new(vec![3.0, 1.0, 1.0]), Poly::constant(5.0)));
+
+    let pairs: [(&[u64], &[u64]); 5] = [
+        (&[3, 1, 4, 1, 5, 2], &[6, 5, 3]),
+        (&[3, 1, 4, 1, 5, 2], &[2, 4]),
+        (&[3, 1, 4, 1, 5, 2], &[5]),
+        (&[0, 0, 0, 0, 0, 1], &[1, 0, 1]),
+        (&[1, 2], &[6, 5, 3])
+    ];
+    for (a, b) in pairs {
+        let (a, b) = (f7(a), f7(b));
+        let (q, r) = EuclideanDomain::div_rem(&a, &b);
+        assert_eq!(&(&q * &b) + &r, a);
+        assert!(r.degree() < b.degree());
+    }
+
+    // gcd((x - 1)(x - 2), (x - 1)(x - 3)) = x - 1, up to a unit
+    let g = f7(&[2, 4, 1]).gcd(&f7(&[3, 3, 1]));
+    let monic = &g * &g.normalizing_unit();
+    assert_eq!(monic, f7(&[6, 1]));
+}
+
+#[test]
+fn test_characteristic_matrix_determinant() {
+    let a = matrix![
+        2i64, -1, 0, 3;
+        1, 4, 2, -2;
+        0, 1, -3, 1;
+        5, 0, 1, 1
+    ];
+    let p = a.characteristic_matrix().determinant();
+    assert_eq!(p, Poly::new(a.characteristic_polynomial()));
+    assert_eq!(p.evaluate(&0), a.determinant());
+}
+
+#[test]
+fn test_poly_with_matrix_coefficients() {
+    let a = matrix![1i64, 2, 0; 0, 1, -1; 3, 0, 2];
+    let scalar = |c: i64| Matrix::new([[c, 0, 0], [0, c, 0], [0, 0, c]]);
+
+    // Cayley-Hamilton, evaluating a polynomial with scalar matrix coefficients
+    let p: Poly<Matrix<i64, 3, 3>> = Poly::new(a.characteristic_polynomial().into_iter().map(scalar).collect());
+    assert!(p.evaluate(&a).is_zero());
+
+    // xI - A has coefficients -A and I
+    let lifted = Poly::from(a.characteristic_matrix());
+    assert_eq!(lifted.coefficients(), &[-a, Matrix::one()]);
+    assert_eq!(Matrix::from(lifted), a.characteristic_matrix());
+}
+
+#[test]
+fn test_poly_matrix_isomorphism() {
+    let m: Matrix<Poly<i64>, 2, 2> = matrix![
+        Poly::new(vec![1, 2]), Poly::new(vec![0, 0, 3]);
+        Poly::new(vec![-1]), Poly::x()
+    ];
+    let n: Matrix<Poly<i64>, 2, 2> = matrix![
+        Poly::new(vec![2, 0, 1]), Poly::one();
+        Poly::new(vec![0, -1]), Poly::new(vec![4, 1])
+    ];
+    let product = Poly::from(&m * &n);
+    assert_eq!(product, &Poly::from(m.clone()) * &Poly::from(n.clone()));
+    assert_eq!(Matrix::from(product), &m * &n);
+}
+
+#[test]
+fn test_invariant_factors_of_characteristic_matrix() {
+    // A Jordan block for 2 next to the eigenvalues 2 and 3
+    let a: Matrix<F7, 4, 4> = Matrix::new([
+        [2, 1, 0, 0],
+        [0, 2, 0, 0],
+        [0, 0, 2, 0],
+        [0, 0, 0, 3]
+    ].map(|row| row.map(F7::from_integer)));
+
+    let factors = a.characteristic_matrix().invariant_factors();
+    // (x - 2), (x - 2)^2 (x - 3), after leading units 1
+    assert_eq!(factors, vec![Poly::one(), Poly::one(), f7(&[5, 1]), f7(&[2, 2, 0, 1])]);
+
+    let product = factors.iter().fold(Poly::one(), |acc, f| &acc * f);
+    assert_eq!(product, a.characteristic_matrix().determinant());
+
+    let minimal = factors.last().unwrap();
+    assert!(a.evaluate_polynomial(minimal.coefficients()).is_zero());
+}
@@ -0,0 +1,58 @@
+#![allow(clippy::assign_op_pattern)]
+
+use matrix_rings::Matrix;
+use num_traits::{One, Pow};
+
+#[test]
+fn test_matrix_pow_fibonacci() {
+    // [[1, 1], [1, 0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]]
+    let fib = Matrix::new([[1u64, 1], [1, 0]]);
+    let result = fib.pow(10);
+    assert_eq!(result, Matrix::new([[89, 55], [55, 34]]));
+
+    let result = fib.pow(90);
+    assert_eq!(result[(0, 1)], 2880067194370816120);
+}
+
+#[test]
+fn test_matrix_pow_zero_is_identity() {
+    let a = Matrix::new([[2, 3], [5, 7]]);
+    assert_eq!(a.pow(0), Matrix::one());
+    assert_eq!(a.pow(1), a);
+}
+
+#[test]
+fn test_matrix_pow_matches_repeated_multiplication() {
+    let a = Matrix::new([[1, -1, 0], [2, 0, 1], [0, 1, 1]]);
+    let mut expected: Matrix<i32, 3, 3> = Matrix::one();
+    for n in 0..12 {
+        assert_eq!(a.pow(n), expected);
+        expected = expected * a;
+    }
+}
+
+#[test]
+fn test_matrix_pow_trait() {
+    let a = Matrix::new([[1, 1], [0, 1]]);
+    assert_eq!(Pow::pow(&a, 5u32), Matrix::new([[1, 5], [0, 1]]));
+    assert_eq!(Pow::pow(&a, 5u64), Matrix::new([[1, 5], [0, 1]]));
+    assert_eq!(Pow::pow(a, 7u32), Matrix::new([[1, 7], [0, 1]]));
+    assert_eq!(Pow::pow(a, 7u64), Matrix::new([[1, 7], [0, 1]]));
+}
+
+#[test]
+fn test_matrix_pow_path_counting() {
+    // Walks of length 3 in the directed cycle 0 -> 1 -> 2 -> 0 with a self loop at 0
+    let adjacency = Matrix::new([[1, 1, 0], [0, 0, 1], [1, 0, 0]]);
+    let walks = adjacency.pow(3);
+    assert_eq!(walks, Matrix::new([[2, 1, 1], [1, 1, 0], [1, 1, 1]]));
+}
+
+#[test]
+fn test_matrix_pow_reduced() {
+    const MOD: u64 = 1_000_000_007;
+    let fib = Matrix::new([[1u64, 1], [1, 0]]);
+    let result = fib.pow_reduced(1_000_000_000_000, |x| x % MOD);
+    // F(10^12) mod 10^9 + 7
+    assert_eq!(result[(0, 1)], 730695249);
+}
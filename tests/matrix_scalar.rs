@@ -0,0 +1,48 @@
+#![allow(clippy::op_ref)]
+
+use matrix_rings::{Matrix, Scalar};
+
+#[test]
+fn test_matrix_scale() {
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let expected = Matrix::new([[3, 6, 9], [12, 15, 18]]);
+    assert_eq!(a.scale_left(&3), expected);
+    assert_eq!(a.scale_right(&3), expected);
+    assert_eq!(a.as_ref().scale_left(&3), expected);
+    assert_eq!(a.as_ref().scale_right(&3), expected);
+}
+
+#[test]
+fn test_scalar_wrapper() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    let expected = Matrix::new([[2, 4], [6, 8]]);
+    assert_eq!(Scalar(2) * a, expected);
+    assert_eq!(Scalar(2) * &a, expected);
+    assert_eq!(Scalar(2) * a.as_ref(), expected);
+    assert_eq!(a * Scalar(2), expected);
+    assert_eq!(&a * Scalar(2), expected);
+    assert_eq!(a.as_ref() * Scalar(2), expected);
+}
+
+#[test]
+fn test_scalar_heterogeneous() {
+    let a: Matrix<f64, 1, 2> = Matrix::new([[1.5, -2.0]]);
+    assert_eq!(Scalar(2.0) * a, Matrix::new([[3.0, -4.0]]));
+}
+
+#[test]
+fn test_scalar_non_commutative_entries() {
+    // With matrix entries, r·A and A·r multiply each block on different sides
+    let r = Matrix::new([[0, 1], [0, 0]]);
+    let block = Matrix::new([[1, 2], [3, 4]]);
+    let a: Matrix<Matrix<i32, 2, 2>, 1, 2> = Matrix::new([[block, r]]);
+
+    let left = Scalar(r) * &a;
+    let right = &a * Scalar(r);
+    assert_eq!(left[(0, 0)], r * block);
+    assert_eq!(right[(0, 0)], block * r);
+    assert_ne!(left, right);
+
+    assert_eq!(a.scale_left(&r), left);
+    assert_eq!(a.scale_right(&r), right);
+}
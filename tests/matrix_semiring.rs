@@ -0,0 +1,92 @@
+#![allow(clippy::op_ref)]
+
+use matrix_rings::{Boolean, Matrix, MaxPlus, MinPlus, Semiring};
+
+const INF: u64 = u64::MAX;
+
+// 0 -> 1 (4), 0 -> 2 (1), 2 -> 1 (2), 1 -> 3 (1), 2 -> 3 (5)
+const EDGES: [(usize, usize, u64); 5] = [(0, 1, 4), (0, 2, 1), (2, 1, 2), (1, 3, 1), (2, 3, 5)];
+
+fn adjacency<R: Semiring>(weight: impl Fn(u64) -> R) -> Matrix<R, 4, 4> {
+    let mut m = Matrix::one();
+    for (i, j, w) in EDGES {
+        m[(i, j)] = weight(w);
+    }
+    m
+}
+
+fn assert_semiring<R: Semiring>() {}
+
+#[test]
+fn test_semiring_types() {
+    assert_semiring::<MinPlus<u64>>();
+    assert_semiring::<MaxPlus<i64>>();
+    assert_semiring::<Boolean>();
+    assert_semiring::<Matrix<Boolean, 3, 3>>();
+
+    assert_eq!(&MinPlus(3u64) + &MinPlus(5), MinPlus(3));
+    assert_eq!(&MinPlus(3u64) * &MinPlus(5), MinPlus(8));
+    assert_eq!(&MinPlus(3u64) * &MinPlus::zero(), MinPlus::zero());
+    assert_eq!(&MaxPlus(3i64) + &MaxPlus(5), MaxPlus(5));
+    assert_eq!(&MaxPlus(-3i64) * &MaxPlus::one(), MaxPlus(-3));
+    assert_eq!(&Boolean(true) + &Boolean(false), Boolean(true));
+    assert_eq!(&Boolean(true) * &Boolean(false), Boolean(false));
+}
+
+#[test]
+fn test_semiring_overflow() {
+    let half = MinPlus(u64::MAX / 2 + 1);
+    assert_eq!(half * half, MinPlus(u64::MAX));
+    assert_eq!(&half * &half, MinPlus::zero());
+    assert_eq!(&MinPlus(u64::MAX - 1) * &MinPlus(1), MinPlus(u64::MAX));
+
+    let low = MaxPlus(i64::MIN / 2 - 1);
+    assert_eq!(low * low, MaxPlus(i64::MIN));
+    assert_eq!(&low * &low, MaxPlus::zero());
+    assert_eq!(&MaxPlus(i64::MAX) * &MaxPlus(1), MaxPlus(i64::MIN));
+}
+
+#[test]
+fn test_all_pairs_shortest_paths() {
+    let a = adjacency(MinPlus);
+    let distances = a.pow(3);
+    let expected = Matrix::new([
+        [0, 3, 1, 4],
+        [INF, 0, INF, 1],
+        [INF, 2, 0, 3],
+        [INF, INF, INF, 0]
+    ].map(|row| row.map(MinPlus)));
+    assert_eq!(distances, expected);
+    // Paths never get longer than DIM - 1 edges, so the powers stabilise
+    assert_eq!(a.pow(10), expected);
+}
+
+#[test]
+fn test_longest_paths_in_dag() {
+    let a = adjacency(|w| MaxPlus(w as i64));
+    let lengths = a.pow(3);
+    assert_eq!(lengths[(0, 1)], MaxPlus(4));
+    assert_eq!(lengths[(0, 3)], MaxPlus(6));
+    assert_eq!(lengths[(2, 3)], MaxPlus(5));
+    assert!(lengths[(3, 0)].is_zero());
+}
+
+#[test]
+fn test_reachability() {
+    let reachable = adjacency(|_| Boolean(true)).pow(3);
+    let expected = Matrix::new([
+        [true, true, true, true],
+        [false, true, false, true],
+        [false, true, true, true],
+        [false, false, false, true]
+    ].map(|row| row.map(Boolean)));
+    assert_eq!(reachable, expected);
+
+    // Without the diagonal, A^k is reachability by walks of exactly k edges
+    let mut walks: Matrix<Boolean, 4, 4> = Matrix::zero();
+    for (i, j, _) in EDGES {
+        walks[(i, j)] = Boolean(true);
+    }
+    assert_eq!(walks.pow(3)[(0, 3)], Boolean(true));
+    assert!(walks.pow(4).is_zero());
+}
@@ -0,0 +1,112 @@
+use matrix_rings::{matrix, EuclideanDomain, Matrix};
+
+fn assert_smith<const ROWS: usize, const COLS: usize>(a: Matrix<i64, ROWS, COLS>) {
+    let (u, d, v) = a.smith_normal_form();
+    assert_eq!(u * a * v, d);
+
+    let (det_u, det_v) = (u.determinant(), v.determinant());
+    assert!(det_u == 1 || det_u == -1);
+    assert!(det_v == 1 || det_v == -1);
+
+    for i in 0..ROWS {
+        for j in 0..COLS {
+            if i != j {
+                assert_eq!(d[(i, j)], 0);
+            }
+        }
+    }
+    let diagonal: Vec<i64> = (0..ROWS.min(COLS)).map(|i| d[(i, i)]).collect();
+    for pair in diagonal.windows(2) {
+        assert!(pair[0] >= 0);
+        assert!(pair[1] == 0 || (pair[0] != 0 && pair[1].div_rem(&pair[0]).1 == 0));
+    }
+}
+
+#[test]
+fn test_smith_normal_form_known() {
+    let a = matrix![
+        2i64, 4, 4;
+        -6, 6, 12;
+        10, 4, 16
+    ];
+    let (_, d, _) = a.smith_normal_form();
+    assert_eq!(d, matrix![2, 0, 0; 0, 2, 0; 0, 0, 156]);
+    assert_eq!(a.invariant_factors(), vec![2, 2, 156]);
+    assert_smith(a);
+}
+
+#[test]
+fn test_smith_normal_form_needs_divisibility_fix() {
+    // diag(2, 3) is diagonal but 2 does not divide 3
+    let a = matrix![2i64, 0; 0, 3];
+    let (_, d, _) = a.smith_normal_form();
+    assert_eq!(d, matrix![1, 0; 0, 6]);
+    assert_smith(a);
+}
+
+#[test]
+fn test_smith_normal_form_rectangular() {
+    assert_smith(matrix![
+        4i64, -6, 2;
+        -2, 3, -1;
+        6, 0, 8;
+        1, 1, 1
+    ]);
+    assert_smith(matrix![
+        0i64, 0, 5, 10;
+        0, 3, 7, -4
+    ]);
+    assert_smith(matrix![0i64, 0; 0, 0]);
+}
+
+#[test]
+fn test_smith_normal_form_associate_entries() {
+    // Row and column passes keep trading pivots of equal size here
+    let a = matrix![4i64, 6; 10, 14];
+    assert_eq!(a.invariant_factors(), vec![2, 2]);
+    assert_smith(a);
+    assert_smith(matrix![2i64, 2; 0, 2]);
+    assert_smith(matrix![-2i64, 2; 2, -2]);
+
+    // Small entries that repeat up to sign keep producing equal-size pivots
+    assert_smith(matrix![
+        -2i64, -2, 2, 0;
+        -4, -1, 2, -2;
+        3, 3, 3, -4
+    ]);
+    assert_smith(matrix![
+        2i64, -2, 2, 2;
+        -2, 2, 4, -2;
+        2, 4, -2, 2
+    ]);
+    assert_smith(matrix![
+        4i64, 4, -4, 1;
+        -3, 4, -3, -4;
+        0, 3, 0, -1
+    ]);
+    assert_smith(matrix![
+        -2i64, -1, 4, 4;
+        2, -3, 3, 0;
+        4, 3, 4, 4
+    ]);
+    assert_smith(matrix![
+        0i64, 0, 4, -1;
+        4, 4, -4, 1;
+        1, -1, -1, -2
+    ]);
+}
+
+#[test]
+fn test_invariant_factors_abelian_group() {
+    // Z^3 / <(2, 0, 0), (0, 4, 2), (0, 2, 4)> ≅ Z/2 × Z/2 × Z/6
+    let relations = matrix![
+        2i64, 0, 0;
+        0, 4, 2;
+        0, 2, 4
+    ];
+    assert_eq!(relations.invariant_factors(), vec![2, 2, 6]);
+
+    // A rank-deficient presentation has a free part: Z^2 / <(2, 4)> ≅ Z/2 × Z
+    let relations = matrix![2i64, 4];
+    assert_eq!(relations.invariant_factors(), vec![2]);
+}
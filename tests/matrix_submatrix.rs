@@ -0,0 +1,85 @@
+use matrix_rings::{matrix, Matrix, SubmatrixRef};
+
+#[test]
+fn test_submatrix() {
+    let m = matrix![
+        1, 2, 3, 4;
+        5, 6, 7, 8;
+        9, 10, 11, 12
+    ];
+    assert_eq!(m.submatrix::<1, 1, 2, 2>(), matrix![6, 7; 10, 11]);
+    assert_eq!(m.submatrix::<0, 3, 3, 1>(), matrix![4; 8; 12]);
+    assert_eq!(m.submatrix::<0, 0, 3, 4>(), m);
+
+    let empty: Matrix<i32, 0, 2> = m.submatrix::<3, 0, 0, 2>();
+    assert_eq!(empty, Matrix::new([]));
+}
+
+#[test]
+fn test_submatrix_view() {
+    let m = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9
+    ];
+    let view: SubmatrixRef<i32, 2, 2> = m.submatrix_view::<1, 0, 2, 2>();
+    assert_eq!(view[(0, 0)], 4);
+    assert_eq!(view[(1, 1)], 8);
+    assert_eq!(view.get((2, 0)), None);
+    assert_eq!(view.to_matrix(), matrix![4, 5; 7, 8]);
+
+    // The view borrows the original entries
+    assert!(std::ptr::eq(&view[(0, 1)], &m[(1, 1)]));
+    assert_eq!(m.as_ref().submatrix_view::<1, 0, 2, 2>(), view);
+}
+
+#[test]
+fn test_minor() {
+    let m = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 9
+    ];
+    assert_eq!(m.minor::<2>(0, 0), matrix![5, 6; 8, 9]);
+    assert_eq!(m.minor::<2>(1, 2), matrix![1, 2; 7, 8]);
+    assert_eq!(m.minor::<2>(2, 1), matrix![1, 3; 4, 6]);
+}
+
+#[test]
+#[should_panic]
+fn test_minor_out_of_range() {
+    let m = matrix![1, 2; 3, 4];
+    m.minor::<1>(2, 0);
+}
+
+#[test]
+fn test_cofactor() {
+    let m = matrix![
+        1, 2, 3;
+        4, 5, 6;
+        7, 8, 10
+    ];
+    for i in 0..3 {
+        for j in 0..3 {
+            let sign = if (i + j) % 2 == 0 { 1 } else { -1 };
+            assert_eq!(m.cofactor(i, j), sign * m.minor::<2>(i, j).determinant());
+        }
+    }
+    assert_eq!(matrix![7].cofactor(0, 0), 1);
+}
+
+#[test]
+fn test_laplace_expansion() {
+    let m = matrix![
+        2, -1, 0, 3;
+        1, 4, 2, -2;
+        0, 1, -3, 1;
+        5, 0, 1, 1
+    ];
+    for i in 0..4 {
+        let along_row = (0..4).fold(0, |acc, j| acc + m[(i, j)] * m.cofactor(i, j));
+        assert_eq!(along_row, m.determinant());
+        let along_column = (0..4).fold(0, |acc, j| acc + m[(j, i)] * m.cofactor(j, i));
+        assert_eq!(along_column, m.determinant());
+    }
+}
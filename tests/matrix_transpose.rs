@@ -0,0 +1,70 @@
+use matrix_rings::{Matrix, MatrixRef, TransposedRef};
+
+#[test]
+fn test_matrix_transpose() {
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let t: Matrix<i32, 3, 2> = a.transpose();
+    assert_eq!(t, Matrix::new([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(t.transpose(), a);
+}
+
+#[test]
+fn test_matrix_transpose_non_copy() {
+    let a = Matrix::new([[String::from("a"), String::from("b")], [String::from("c"), String::from("d")]]);
+    let t = a.transpose();
+    assert_eq!(t[(0, 1)], "c");
+    assert_eq!(t[(1, 0)], "b");
+}
+
+#[test]
+fn test_matrix_ref_transpose() {
+    let vals = [[1, 2], [3, 4], [5, 6]];
+    let a = MatrixRef::new(&vals);
+    assert_eq!(a.transpose(), Matrix::new([[1, 3, 5], [2, 4, 6]]));
+}
+
+#[test]
+fn test_transposed_view_indexing() {
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let view: TransposedRef<i32, 3, 2> = a.transposed_view();
+    for i in 0..3 {
+        for j in 0..2 {
+            assert_eq!(view[(i, j)], a[(j, i)]);
+        }
+    }
+    assert_eq!(view.to_matrix(), a.transpose());
+    assert_eq!(view.transposed_view(), a.as_ref());
+}
+
+#[test]
+fn test_transposed_view_multiplication() {
+    // A * B^T without materialising B^T
+    let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
+    let b = Matrix::new([[7, 8, 9], [1, 0, 1]]);
+    let result = a.as_ref() * b.transposed_view();
+    assert_eq!(result, a * b.transpose());
+
+    // A^T * A is symmetric
+    let gram = a.transposed_view() * a.as_ref();
+    assert_eq!(gram, gram.transpose());
+
+    let c = Matrix::new([[1, 2], [3, 4], [5, 6], [7, 8]]);
+    let result = a.transposed_view() * c.transposed_view();
+    assert_eq!(result, (c * a).transpose());
+}
+
+#[test]
+fn test_transposed_view_addition_and_subtraction() {
+    let a = Matrix::new([[1, 2], [3, 4]]);
+    let b = Matrix::new([[5, 6], [7, 8]]);
+
+    assert_eq!(a.as_ref() + b.transposed_view(), Matrix::new([[6, 9], [9, 12]]));
+    assert_eq!(a.transposed_view() + b.as_ref(), Matrix::new([[6, 9], [9, 12]]));
+    assert_eq!(a.transposed_view() + b.transposed_view(), (a + b).transpose());
+
+    assert_eq!(a.as_ref() - a.transposed_view(), Matrix::new([[0, -1], [1, 0]]));
+    assert_eq!(a.transposed_view() - b.as_ref(), Matrix::new([[-4, -3], [-5, -4]]));
+    assert_eq!(a.transposed_view() - b.transposed_view(), (a - b).transpose());
+
+    assert_eq!(-a.transposed_view(), (-a).transpose());
+}
@@ -0,0 +1,106 @@
+#![allow(clippy::op_ref)]
+
+use matrix_rings::{matrix, Matrix, Zmod};
+use num_traits::{One, Zero};
+
+const P: u64 = 1_000_000_007;
+type Fp = Zmod<P>;
+
+// Values around the moduli below and the edges of the u64 range
+const SAMPLES: [u64; 14] = [
+    0,
+    1,
+    2,
+    3,
+    1_000_000_006,
+    1_000_000_007,
+    1 << 32,
+    4_294_967_311,
+    0x1234_5678_9ABC_DEF0,
+    1 << 63,
+    0x9E37_79B9_7F4A_7C15,
+    18_446_744_073_709_551_557,
+    u64::MAX - 1,
+    u64::MAX
+];
+
+#[test]
+fn test_zmod_arithmetic() {
+    let a = Fp::new(P - 1);
+    let b = Fp::new(5);
+    assert_eq!((&a + &b).value(), 4);
+    assert_eq!((&b - &a).value(), 6);
+    assert_eq!((&a * &a).value(), 1);
+    assert_eq!((-&b).value(), P - 5);
+    assert_eq!(-Fp::zero(), Fp::zero());
+    assert_eq!(Fp::from(-3i64), Fp::new(P - 3));
+    assert_eq!(Fp::new(P + 2), Fp::new(2));
+    assert_eq!(Zmod::<1>::one(), Zmod::<1>::zero());
+    assert_eq!(format!("{}", Fp::new(42)), "42");
+}
+
+#[test]
+fn test_zmod_reduction_matches_remainder() {
+    fn check<const N: u64>() {
+        for x in SAMPLES {
+            for y in SAMPLES {
+                let expected = (x as u128 % N as u128) * (y as u128 % N as u128) % N as u128;
+                assert_eq!((Zmod::<N>::new(x) * Zmod::<N>::new(y)).value() as u128, expected);
+            }
+        }
+    }
+    check::<2>();
+    check::<P>();
+    check::<4_294_967_311>();
+    check::<18_446_744_073_709_551_557>();
+    check::<{ u64::MAX }>();
+}
+
+#[test]
+fn test_zmod_inverse() {
+    for x in SAMPLES.map(Fp::new) {
+        match x.is_zero() {
+            true => assert_eq!(x.inverse(), None),
+            false => assert_eq!(x * x.inverse().unwrap(), Fp::one())
+        }
+    }
+    // Fermat's little theorem
+    assert_eq!(Fp::new(3).inverse(), Some(Fp::new(3).pow(P - 2)));
+
+    assert_eq!(Zmod::<12>::new(4).inverse(), None);
+    assert_eq!(Zmod::<12>::new(5).inverse(), Some(Zmod::new(5)));
+    assert_eq!(Zmod::<12>::new(0).inverse(), None);
+}
+
+#[test]
+fn test_matrix_zmod_fibonacci() {
+    let fib: Matrix<Fp, 2, 2> = matrix![Fp::one(), Fp::one(); Fp::one(), Fp::zero()];
+
+    let (mut a, mut b) = (0u64, 1u64);
+    for _ in 0..1000 {
+        (a, b) = (b, (a + b) % P);
+    }
+    assert_eq!(fib.pow(1000)[(0, 1)].value(), a);
+
+    // Pisano period: F(n + 2(p + 1)) = F(n) for p = 10^9 + 7, since p = 2 mod 5
+    let n = 123_456;
+    assert_eq!(fib.pow(n)[(0, 1)], fib.pow(n + 2 * (P + 1))[(0, 1)]);
+}
+
+#[test]
+fn test_matrix_zmod_inverse() {
+    let a: Matrix<Fp, 3, 3> = Matrix::new([
+        [2, 0, 1],
+        [1, 3, 2],
+        [1, 1, 2]
+    ].map(|row| row.map(Fp::new)));
+    assert_eq!(a.determinant(), Fp::new(6));
+
+    let inverse = a.try_inverse_with(Fp::inverse).unwrap();
+    assert_eq!(&a * &inverse, Matrix::one());
+    assert_eq!(&inverse * &a, Matrix::one());
+
+    // Invertible over the rationals, but not modulo 2
+    let b: Matrix<Zmod<2>, 2, 2> = Matrix::new([[1, 1], [1, 3]].map(|row| row.map(Zmod::new)));
+    assert_eq!(b.try_inverse_with(Zmod::inverse), None);
+}